@@ -0,0 +1,64 @@
+//! Times the Fibonacci functions against each other as n grows.
+//!
+//! Run with `cargo bench`, then open `target/criterion/report/index.html`.
+//! Each group plots every function over the same values of n on a
+//! logarithmic scale, so you can see where the naive recursion, which is
+//! quick for tiny n, falls behind the others and never catches up again.
+
+use std::hint::black_box;
+
+use branches::fibonacci;
+use criterion::{AxisScale, BenchmarkId, Criterion, PlotConfiguration, criterion_group, criterion_main};
+
+/// The functions that go up one number at a time, plus `matrix`, for n
+/// small enough that the recursion still finishes: by 30 it already takes
+/// milliseconds a call, and every step up multiplies that by about 1.6.
+fn small_n(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fibonacci small n");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    for n in [1, 2, 5, 10, 15, 20, 25, 30] {
+        group.bench_with_input(BenchmarkId::new("recursive", n), &n, |b, &n| {
+            b.iter(|| fibonacci::recursive(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("iterative", n), &n, |b, &n| {
+            b.iter(|| fibonacci::iterative(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("memoized", n), &n, |b, &n| {
+            b.iter(|| fibonacci::memoized(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("matrix", n), &n, |b, &n| {
+            b.iter(|| fibonacci::matrix(black_box(n)))
+        });
+    }
+
+    group.finish();
+}
+
+/// Without the recursion, all the way up to the largest F(n) a `u128`
+/// holds. Here the n steps of `iterative` and `memoized` lose to the log2(n)
+/// steps of `matrix` and `fast_doubling`.
+fn large_n(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fibonacci large n");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    for n in [10, 25, 50, 100, 150, fibonacci::MAX_U128_N] {
+        group.bench_with_input(BenchmarkId::new("iterative", n), &n, |b, &n| {
+            b.iter(|| fibonacci::iterative(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("memoized", n), &n, |b, &n| {
+            b.iter(|| fibonacci::memoized(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("matrix", n), &n, |b, &n| {
+            b.iter(|| fibonacci::matrix(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("fast_doubling", n), &n, |b, &n| {
+            b.iter(|| fibonacci::fast_doubling(black_box(n)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, small_n, large_n);
+criterion_main!(benches);
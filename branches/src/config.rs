@@ -0,0 +1,203 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::countdown;
+use crate::rules::Mode;
+
+pub const USAGE: &str = "\
+Usage: branches
+       branches sequence <NAME> [--take <N>] [--skip <N>] [--start <N>]
+       branches countdown <DURATION> [-- <COMMAND>...]
+       branches trace [--dot]
+       branches rules <FILE> [--from <N>] [--to <N>] [--mode <first|all>]
+
+Without a command, runs the examples from the chapter.
+
+Sequences:
+  fibonacci, lucas, tribonacci, pell, collatz
+
+Countdown:
+  <DURATION>   How long to count down: 90s, 5m, 1h30m, or plain seconds,
+               up to 99h
+  <COMMAND>    Run this when the countdown reaches zero
+  Space pauses and resumes, q or Ctrl-C cancels.
+
+Trace:
+  Replays a traced copy of the chapter's loops, showing every iteration
+  and break. The copy is kept by hand next to the loops `branches` runs.
+  --dot        Print a Graphviz diagram instead of a timeline
+
+Rules:
+  <FILE>       Rules in TOML; see rules/fizzbuzz.toml
+  --from <N>   First number to evaluate [default: 1]
+  --to <N>     Last number to evaluate [default: 15]
+  --mode <M>   first: only the first matching rule prints, like else if;
+               all: every matching rule does [default: from the file, or first]
+
+Options:
+  --take <N>   How many terms to print [default: 10]
+  --skip <N>   Terms to jump over before printing [default: 0]
+  --start <N>  Where `collatz` starts [default: 27]
+  -h, --help   Print this help";
+
+/// The sequences `sequence` can print; see `branches::sequences`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sequence {
+    Fibonacci,
+    Lucas,
+    Tribonacci,
+    Pell,
+    Collatz { start: u64 },
+}
+
+impl FromStr for Sequence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fibonacci" => Ok(Sequence::Fibonacci),
+            "lucas" => Ok(Sequence::Lucas),
+            "tribonacci" => Ok(Sequence::Tribonacci),
+            "pell" => Ok(Sequence::Pell),
+            "collatz" => Ok(Sequence::Collatz { start: 27 }),
+            _ => Err(format!(
+                "unknown sequence '{s}' (expected fibonacci, lucas, tribonacci, pell or collatz)"
+            )),
+        }
+    }
+}
+
+/// What the program was asked to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// The chapter's examples, one after the other.
+    Demo,
+    Sequence {
+        sequence: Sequence,
+        skip: usize,
+        take: usize,
+    },
+    Countdown {
+        duration: Duration,
+        /// The program and its arguments; empty to just print LIFTOFF.
+        command: Vec<String>,
+    },
+    /// Trace the chapter's loops, as a timeline or a Graphviz diagram.
+    Trace { dot: bool },
+    Rules {
+        file: PathBuf,
+        from: i64,
+        to: i64,
+        /// Overrides the mode in the file.
+        mode: Option<Mode>,
+    },
+}
+
+impl Command {
+    /// Builds the command from the command line arguments, skipping the
+    /// program name.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+        args.next();
+
+        let Some(command) = args.next() else {
+            return Ok(Command::Demo);
+        };
+
+        match command.as_str() {
+            "sequence" => {
+                let mut sequence: Sequence = args
+                    .next()
+                    .ok_or("sequence needs the name of a sequence")?
+                    .parse()?;
+                let mut skip = 0;
+                let mut take = 10;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--take" => take = number(&mut args, &arg)?,
+                        "--skip" => skip = number(&mut args, &arg)?,
+                        "--start" => match &mut sequence {
+                            Sequence::Collatz { start } => {
+                                *start = number(&mut args, &arg)?;
+                                if *start == 0 {
+                                    return Err(String::from("--start must be at least 1"));
+                                }
+                            }
+                            _ => return Err(String::from("--start only applies to collatz")),
+                        },
+                        _ => return Err(format!("unexpected argument '{arg}'\n\n{USAGE}")),
+                    }
+                }
+
+                Ok(Command::Sequence {
+                    sequence,
+                    skip,
+                    take,
+                })
+            }
+            "countdown" => {
+                let duration = args.next().ok_or("countdown needs a duration, like 90s or 5m")?;
+                let duration = countdown::parse_duration(&duration)?;
+                if duration.is_zero() {
+                    return Err(String::from("countdown needs a duration longer than zero"));
+                }
+
+                let command = match args.next().as_deref() {
+                    None => Vec::new(),
+                    Some("--") => args.collect(),
+                    Some(arg) => return Err(format!("unexpected argument '{arg}'\n\n{USAGE}")),
+                };
+
+                Ok(Command::Countdown { duration, command })
+            }
+            "trace" => match args.next().as_deref() {
+                None => Ok(Command::Trace { dot: false }),
+                Some("--dot") => Ok(Command::Trace { dot: true }),
+                Some(arg) => Err(format!("unexpected argument '{arg}'\n\n{USAGE}")),
+            },
+            "rules" => {
+                let file = args.next().ok_or("rules needs a file of rules")?;
+                let mut from = 1;
+                let mut to = 15;
+                let mut mode = None;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--from" => from = integer(&mut args, &arg)?,
+                        "--to" => to = integer(&mut args, &arg)?,
+                        "--mode" => {
+                            mode = Some(args.next().ok_or("--mode needs a value")?.parse()?)
+                        }
+                        _ => return Err(format!("unexpected argument '{arg}'\n\n{USAGE}")),
+                    }
+                }
+                if from > to {
+                    return Err(format!("--from ({from}) must not be greater than --to ({to})"));
+                }
+
+                Ok(Command::Rules {
+                    file: PathBuf::from(file),
+                    from,
+                    to,
+                    mode,
+                })
+            }
+            _ => Err(format!("unknown command '{command}'\n\n{USAGE}")),
+        }
+    }
+}
+
+fn number<T: FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<T, String> {
+    let value = args.next().ok_or(format!("{flag} needs a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("{flag} expects a non-negative number, got '{value}'"))
+}
+
+fn integer(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<i64, String> {
+    let value = args.next().ok_or(format!("{flag} needs a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("{flag} expects a whole number, got '{value}'"))
+}
@@ -0,0 +1,250 @@
+//! `branches countdown`: the chapter's `while number != 0` LIFTOFF loop,
+//! grown into a timer you can actually use.
+//!
+//! The timer keeps a deadline rather than counting sleeps, so however late
+//! a tick is drawn, the countdown doesn't drift from the clock on the wall.
+
+use std::io::{self, IsTerminal, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, queue, terminal};
+
+/// How often the plain countdown checks whether Ctrl-C was pressed.
+const CTRL_C_CHECK: Duration = Duration::from_millis(100);
+
+/// The longest countdown there is. Anything longer is more likely a typo
+/// than a plan, and a deadline that far out might not fit in an `Instant`.
+pub const MAX_DURATION: Duration = Duration::from_secs(99 * 60 * 60);
+
+/// Reads durations like `90s`, `5m`, `1h30m` or a bare `90` (seconds), up
+/// to `MAX_DURATION`.
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let invalid = || format!("'{text}' is not a duration (try 90s, 5m or 1h30m, at most 99h)");
+
+    if text.is_empty() {
+        return Err(invalid());
+    }
+    if text.bytes().all(|byte| byte.is_ascii_digit()) {
+        return text
+            .parse()
+            .map(Duration::from_secs)
+            .ok()
+            .filter(|&total| total <= MAX_DURATION)
+            .ok_or_else(invalid);
+    }
+
+    let mut total = Duration::ZERO;
+    let mut rest = text;
+    while !rest.is_empty() {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let amount: u64 = rest[..digits].parse().map_err(|_| invalid())?;
+        let unit = rest[digits..].chars().next().ok_or_else(invalid)?;
+        let seconds = match unit {
+            'h' => amount.checked_mul(60 * 60),
+            'm' => amount.checked_mul(60),
+            's' => Some(amount),
+            _ => return Err(invalid()),
+        }
+        .ok_or_else(invalid)?;
+
+        total = total
+            .checked_add(Duration::from_secs(seconds))
+            .ok_or_else(invalid)?;
+        rest = &rest[digits + unit.len_utf8()..];
+    }
+
+    if total > MAX_DURATION {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+/// `mm:ss`, or `h:mm:ss` from an hour up. Part of a second counts as a
+/// whole one, so the display reaches 00:00 exactly at zero.
+pub fn format(remaining: Duration) -> String {
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+/// How long until `format(remaining)` shows one second less.
+pub fn until_next_tick(remaining: Duration) -> Duration {
+    match remaining.subsec_nanos() {
+        0 if remaining.is_zero() => Duration::ZERO,
+        0 => Duration::from_secs(1),
+        nanos => Duration::from_nanos(u64::from(nanos)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Running { deadline: Instant },
+    Paused { remaining: Duration },
+}
+
+/// A countdown that can be paused. It is told the time rather than
+/// looking at the clock itself, so it can be tested without waiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timer {
+    state: State,
+}
+
+impl Timer {
+    pub fn start(total: Duration, now: Instant) -> Timer {
+        Timer {
+            state: State::Running {
+                deadline: now + total,
+            },
+        }
+    }
+
+    pub fn remaining(&self, now: Instant) -> Duration {
+        match self.state {
+            State::Running { deadline } => deadline.saturating_duration_since(now),
+            State::Paused { remaining } => remaining,
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        matches!(self.state, State::Paused { .. })
+    }
+
+    /// Pauses a running timer, or resumes a paused one where it left off.
+    pub fn toggle(&mut self, now: Instant) {
+        self.state = match self.state {
+            State::Running { .. } => State::Paused {
+                remaining: self.remaining(now),
+            },
+            State::Paused { remaining } => State::Running {
+                deadline: now + remaining,
+            },
+        };
+    }
+}
+
+/// How a countdown ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ending {
+    Finished,
+    Cancelled { remaining: Duration },
+}
+
+/// Counts `total` down to zero on the terminal.
+///
+/// When both ends are a terminal, the time is redrawn in place and keys
+/// work: space (or `p`) pauses and resumes, `q`, Esc and Ctrl-C cancel.
+/// Otherwise, say with the output going to a file, there is one line per
+/// second and only Ctrl-C.
+pub fn run(total: Duration) -> io::Result<Ending> {
+    if io::stdin().is_terminal() && io::stdout().is_terminal() {
+        run_interactive(total)
+    } else {
+        run_plain(total)
+    }
+}
+
+/// Leaves raw mode when dropped, even if the countdown fails half way.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> io::Result<RawMode> {
+        terminal::enable_raw_mode()?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn run_interactive(total: Duration) -> io::Result<Ending> {
+    let mut stdout = io::stdout();
+    println!("Space to pause, q to quit.");
+
+    // Raw mode hands over every key press as it happens, instead of a line
+    // at a time, and turns Ctrl-C into just another key.
+    let raw_mode = RawMode::enable()?;
+    let mut timer = Timer::start(total, Instant::now());
+
+    let ending = loop {
+        let remaining = timer.remaining(Instant::now());
+        let status = if timer.is_paused() { " (paused)" } else { "" };
+        queue!(
+            stdout,
+            cursor::MoveToColumn(0),
+            terminal::Clear(terminal::ClearType::CurrentLine)
+        )?;
+        write!(stdout, "{}{status}", format(remaining))?;
+        stdout.flush()?;
+
+        if remaining.is_zero() {
+            break Ending::Finished;
+        }
+
+        // While paused there is nothing to redraw until a key comes.
+        let wait = if timer.is_paused() {
+            Duration::from_secs(60 * 60)
+        } else {
+            until_next_tick(remaining)
+        };
+        if !event::poll(wait)? {
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            match key.code {
+                KeyCode::Char(' ' | 'p') => timer.toggle(Instant::now()),
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    break Ending::Cancelled { remaining };
+                }
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    break Ending::Cancelled { remaining };
+                }
+                _ => {}
+            }
+        }
+    };
+
+    drop(raw_mode);
+    println!();
+    Ok(ending)
+}
+
+fn run_plain(total: Duration) -> io::Result<Ending> {
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&interrupted);
+    ctrlc::set_handler(move || flag.store(true, Ordering::SeqCst)).map_err(io::Error::other)?;
+
+    let timer = Timer::start(total, Instant::now());
+    let mut remaining = timer.remaining(Instant::now());
+
+    while !remaining.is_zero() {
+        println!("{}", format(remaining));
+
+        // Sleep until the next second, a little at a time so Ctrl-C is
+        // noticed quickly.
+        let tick = format(remaining);
+        while format(remaining) == tick && !remaining.is_zero() {
+            if interrupted.load(Ordering::SeqCst) {
+                return Ok(Ending::Cancelled { remaining });
+            }
+            thread::sleep(until_next_tick(remaining).min(CTRL_C_CHECK));
+            remaining = timer.remaining(Instant::now());
+        }
+    }
+
+    println!("{}", format(remaining));
+    Ok(Ending::Finished)
+}
@@ -0,0 +1,168 @@
+//! Several ways to compute the Fibonacci numbers, from the chapter's naive
+//! recursion to algorithms that need only O(log n) steps.
+//!
+//! F(0) = 0, F(1) = 1 and F(n) = F(n - 1) + F(n - 2).
+//!
+//! The fast versions return a `u128`, which holds every Fibonacci number up
+//! to F(186). Past that, use `checked_u128` to find out, or `big` to get
+//! the exact value however large it is.
+
+use num_bigint::BigUint;
+
+/// The largest `n` for which F(n) fits in a `u64`.
+pub const MAX_U64_N: u32 = 93;
+
+/// The largest `n` for which F(n) fits in a `u128`.
+pub const MAX_U128_N: u32 = 186;
+
+/// The definition turned straight into code, as in the chapter.
+///
+/// Every call makes two more, so the work grows like F(n) itself: about
+/// 1.6^n calls. It also overflows the `u32` past F(47).
+pub fn recursive(n: u32) -> u32 {
+    if n == 0 {
+        0
+    } else if n == 1 {
+        1
+    } else {
+        recursive(n-1) + recursive(n-2)
+    }
+}
+
+/// Walks up from F(0), keeping only the last two numbers: n steps.
+pub fn iterative(n: u32) -> u128 {
+    assert_fits(n);
+
+    let (mut current, mut next) = (0u128, 1u128);
+    for _ in 0..n {
+        // `next` runs one number ahead, so it overflows one step before
+        // `current` would. Wrapping keeps that harmless; see `matrix`.
+        (current, next) = (next, current.wrapping_add(next));
+    }
+    current
+}
+
+/// The recursive definition again, but every number is computed once and
+/// remembered, which brings the 1.6^n calls down to n.
+pub fn memoized(n: u32) -> u128 {
+    assert_fits(n);
+
+    fn fib(n: usize, memo: &mut Vec<Option<u128>>) -> u128 {
+        if let Some(known) = memo[n] {
+            return known;
+        }
+        let value = fib(n - 1, memo) + fib(n - 2, memo);
+        memo[n] = Some(value);
+        value
+    }
+
+    let mut memo = vec![None; n as usize + 2];
+    memo[0] = Some(0);
+    memo[1] = Some(1);
+    fib(n as usize, &mut memo)
+}
+
+type Matrix = [[u128; 2]; 2];
+
+fn multiply(a: Matrix, b: Matrix) -> Matrix {
+    let cell = |row: usize, column: usize| {
+        a[row][0]
+            .wrapping_mul(b[0][column])
+            .wrapping_add(a[row][1].wrapping_mul(b[1][column]))
+    };
+    [[cell(0, 0), cell(0, 1)], [cell(1, 0), cell(1, 1)]]
+}
+
+/// Raises the matrix [[1, 1], [1, 0]] to the n-th power, which is
+/// [[F(n + 1), F(n)], [F(n), F(n - 1)]], by repeated squaring: about
+/// log2(n) matrix products.
+///
+/// F(n + 1) may not fit even when F(n) does, so the arithmetic wraps. That
+/// is harmless: wrapping computes everything modulo 2^128, and a number
+/// below 2^128 is its own remainder, so F(n) still comes out exact.
+pub fn matrix(n: u32) -> u128 {
+    assert_fits(n);
+
+    let mut result: Matrix = [[1, 0], [0, 1]];
+    let mut power: Matrix = [[1, 1], [1, 0]];
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            result = multiply(result, power);
+        }
+        power = multiply(power, power);
+        n >>= 1;
+    }
+    result[0][1]
+}
+
+/// The matrix method with the redundant work taken out. From F(k) and
+/// F(k + 1):
+///
+/// - F(2k)     = F(k) * (2 * F(k + 1) - F(k))
+/// - F(2k + 1) = F(k)^2 + F(k + 1)^2
+///
+/// so each bit of n, from the highest down, doubles k (and adds one if the
+/// bit is set). Like `matrix`, the arithmetic wraps.
+pub fn fast_doubling(n: u32) -> u128 {
+    assert_fits(n);
+
+    let (mut a, mut b) = (0u128, 1u128);
+    for bit in (0..u32::BITS - n.leading_zeros()).rev() {
+        let c = a.wrapping_mul(b.wrapping_mul(2).wrapping_sub(a));
+        let d = a.wrapping_mul(a).wrapping_add(b.wrapping_mul(b));
+        (a, b) = if n >> bit & 1 == 1 {
+            (d, c.wrapping_add(d))
+        } else {
+            (c, d)
+        };
+    }
+    a
+}
+
+/// F(n), or `None` if it doesn't fit in a `u64`.
+pub fn checked(n: u32) -> Option<u64> {
+    checked_u128(n)?.try_into().ok()
+}
+
+/// F(n), or `None` if it doesn't fit in a `u128`.
+pub fn checked_u128(n: u32) -> Option<u128> {
+    if n == 0 {
+        return Some(0);
+    }
+
+    // Stop at F(n) itself rather than one past it, so the first overflow
+    // really means F(n) is too large.
+    let (mut current, mut next) = (0u128, 1u128);
+    for _ in 1..n {
+        (current, next) = (next, current.checked_add(next)?);
+    }
+    Some(next)
+}
+
+/// F(n) exactly, however many digits it has. F(10_000) has 2090.
+///
+/// This is `fast_doubling` on big integers. Multiplying big numbers is
+/// no longer a single step, but it still needs only log2(n) rounds.
+pub fn big(n: u32) -> BigUint {
+    let (mut a, mut b) = (BigUint::from(0u8), BigUint::from(1u8));
+    for bit in (0..u32::BITS - n.leading_zeros()).rev() {
+        // b >= a, so the subtraction can't go below zero.
+        let c = &a * (&b * 2u8 - &a);
+        let d = &a * &a + &b * &b;
+        (a, b) = if n >> bit & 1 == 1 {
+            let sum = &c + &d;
+            (d, sum)
+        } else {
+            (c, d)
+        };
+    }
+    a
+}
+
+fn assert_fits(n: u32) {
+    assert!(
+        n <= MAX_U128_N,
+        "F({n}) doesn't fit in a u128; use checked_u128 or big"
+    );
+}
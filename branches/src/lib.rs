@@ -0,0 +1,8 @@
+//! Code from the control flow chapter that is worth more than one look.
+
+pub mod config;
+pub mod countdown;
+pub mod fibonacci;
+pub mod rules;
+pub mod sequences;
+pub mod trace;
@@ -1,4 +1,51 @@
+use std::env;
+use std::io;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+use branches::config::{Command, Sequence, USAGE};
+use branches::countdown::{self, Ending};
+use branches::rules::{Mode, RuleSet};
+use branches::{fibonacci, sequences, trace};
+
 fn main() {
+    // Anything after `--` belongs to the countdown's command.
+    if env::args()
+        .take_while(|arg| arg != "--")
+        .any(|arg| arg == "-h" || arg == "--help")
+    {
+        println!("{USAGE}");
+        return;
+    }
+
+    let command = Command::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+
+    match command {
+        Command::Demo => demo(),
+        Command::Sequence {
+            sequence,
+            skip,
+            take,
+        } => print_sequence(sequence, skip, take),
+        Command::Countdown { duration, command } => run_countdown(duration, &command),
+        Command::Trace { dot } => {
+            let trace = trace::chapter();
+            print!("{}", if dot { trace.dot() } else { trace.timeline() });
+        }
+        Command::Rules {
+            file,
+            from,
+            to,
+            mode,
+        } => run_rules(&file, from, to, mode),
+    }
+}
+
+fn demo() {
     // conditions
     let number = 3;
 
@@ -70,21 +117,111 @@ fn main() {
     }
 
     for num in (1..4).rev() {
-        println!("{number}");
+        println!("{num}");
     }
 
     println!("LIFTOFF!!!");
 
-    let x = fibonacci(6);
+    let x = fibonacci::recursive(6);
     println!("Fibonacci(6) is {x}");
+
+    // The recursive version would still be running; see src/fibonacci.rs
+    // for how the fast ones work.
+    let x = fibonacci::fast_doubling(100);
+    println!("Fibonacci(100) is {x}");
+
+    let digits = fibonacci::big(10_000).to_string().len();
+    println!("Fibonacci(10000) has {digits} digits");
+
+    // the sequences in src/sequences.rs are iterators, so `for` can walk them
+    for term in sequences::lucas().take(5) {
+        println!("Lucas: {term}");
+    }
+
+    let steps = sequences::collatz(27).count() - 1;
+    println!("Collatz from 27 takes {steps} steps to reach 1");
 }
 
-fn fibonacci(n: u32) -> u32 {
-    if n == 0 {
-        0
-    } else if n == 1 {
-        1
-    } else {
-        fibonacci(n-1) + fibonacci(n-2)
+fn print_sequence(sequence: Sequence, skip: usize, take: usize) {
+    let terms: Box<dyn Iterator<Item = u64>> = match sequence {
+        Sequence::Fibonacci => Box::new(sequences::fibonacci()),
+        Sequence::Lucas => Box::new(sequences::lucas()),
+        Sequence::Tribonacci => Box::new(sequences::tribonacci()),
+        Sequence::Pell => Box::new(sequences::pell()),
+        Sequence::Collatz { start } => Box::new(sequences::collatz(start)),
+    };
+
+    // `skip` calls `nth` once, so it jumps rather than walks.
+    let mut printed = 0;
+    let mut last = None;
+    for term in terms.skip(skip).take(take) {
+        println!("{term}");
+        printed += 1;
+        last = Some(term);
+    }
+    if printed == take {
+        return;
     }
-}
\ No newline at end of file
+
+    match (sequence, last) {
+        // Reaching 1 is how a Collatz sequence is meant to end.
+        (Sequence::Collatz { .. }, Some(1) | None) => {}
+        (Sequence::Collatz { .. }, Some(last)) => {
+            eprintln!("The sequence stops at {last}: 3n + 1 doesn't fit in a u64.")
+        }
+        _ => eprintln!("The sequence ends there: the next term doesn't fit in a u64."),
+    }
+}
+
+fn run_countdown(duration: Duration, command: &[String]) {
+    match countdown::run(duration) {
+        Ok(Ending::Finished) => println!("LIFTOFF!!!"),
+        Ok(Ending::Cancelled { remaining }) => {
+            eprintln!("Cancelled with {} to go.", countdown::format(remaining));
+            // What a shell reports for a program stopped by Ctrl-C.
+            process::exit(130);
+        }
+        Err(err) => {
+            eprintln!("The countdown failed: {err}");
+            process::exit(1);
+        }
+    }
+
+    let Some((program, args)) = command.split_first() else {
+        return;
+    };
+    match process::Command::new(program).args(args).status() {
+        // Pass the command's exit code on, so scripts can tell how it went.
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(err) => {
+            eprintln!("Could not run {program}: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+fn run_rules(file: &Path, from: i64, to: i64, mode: Option<Mode>) {
+    let rules = RuleSet::load(file).unwrap_or_else(|err| {
+        eprintln!("Could not read the rules: {err}");
+        process::exit(1);
+    });
+
+    let mode = mode.unwrap_or(rules.mode);
+    let report = rules
+        .run(from..=to, mode, &mut io::stdout().lock())
+        .unwrap_or_else(|err| {
+            eprintln!("Could not print the results: {err}");
+            process::exit(1);
+        });
+
+    // The ordering pitfall: a rule that would have matched, but an earlier
+    // one always matched as well.
+    for rule in report.shadowed() {
+        eprintln!(
+            "Rule {} ({}) matched {} numbers but never printed: an earlier rule always matched first.",
+            rule + 1,
+            rules.rules[rule],
+            report.matched[rule]
+        );
+    }
+}
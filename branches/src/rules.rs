@@ -0,0 +1,290 @@
+//! `branches rules`: an `if`/`else if` chain you write in a TOML file
+//! instead of in code.
+//!
+//! Each rule pairs conditions on a number with what to print for it:
+//!
+//! ```toml
+//! mode = "first"
+//!
+//! [[rule]]
+//! when = { divisible_by = 15 }
+//! output = "FizzBuzz"
+//!
+//! [[rule]]
+//! when = { between = [1, 10], divisible_by = 2 }
+//! output = "small and even"
+//! ```
+//!
+//! All the conditions of a rule must hold for it to match, and a rule
+//! without any matches everything, like a final `else`. `{n}` in an output
+//! is replaced by the number.
+//!
+//! In `first` mode only the first matching rule counts, just like a chain
+//! of `if` and `else if`, so the order matters. In `all` mode every
+//! matching rule adds its output, like separate `if`s one after another.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+/// Which matching rules produce output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// Only the first, like `if` / `else if`.
+    #[default]
+    First,
+    /// All of them, one after the other, like separate `if`s.
+    All,
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Mode::First => "first",
+            Mode::All => "all",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "first" => Ok(Mode::First),
+            "all" => Ok(Mode::All),
+            _ => Err(format!("unknown mode '{s}' (expected first or all)")),
+        }
+    }
+}
+
+/// One condition on the number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predicate {
+    DivisibleBy(i64),
+    /// Both ends included.
+    Between(i64, i64),
+    LessThan(i64),
+    GreaterThan(i64),
+    Equals(i64),
+}
+
+impl Predicate {
+    pub fn holds(self, n: i64) -> bool {
+        match self {
+            // `%` would overflow on i64::MIN % -1; wrapping gives the 0.
+            Predicate::DivisibleBy(divisor) => n.wrapping_rem(divisor) == 0,
+            Predicate::Between(low, high) => (low..=high).contains(&n),
+            Predicate::LessThan(bound) => n < bound,
+            Predicate::GreaterThan(bound) => n > bound,
+            Predicate::Equals(value) => n == value,
+        }
+    }
+}
+
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Predicate::DivisibleBy(divisor) => write!(f, "n % {divisor} == 0"),
+            Predicate::Between(low, high) => write!(f, "{low} <= n <= {high}"),
+            Predicate::LessThan(bound) => write!(f, "n < {bound}"),
+            Predicate::GreaterThan(bound) => write!(f, "n > {bound}"),
+            Predicate::Equals(value) => write!(f, "n == {value}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub when: Vec<Predicate>,
+    pub output: String,
+}
+
+impl Rule {
+    pub fn matches(&self, n: i64) -> bool {
+        self.when.iter().all(|predicate| predicate.holds(n))
+    }
+
+    pub fn output(&self, n: i64) -> String {
+        self.output.replace("{n}", &n.to_string())
+    }
+}
+
+/// Reads as the `if` it stands for: `if n % 3 == 0 && n > 10`.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.when.is_empty() {
+            return write!(f, "else {:?}", self.output);
+        }
+
+        let conditions: Vec<String> = self.when.iter().map(Predicate::to_string).collect();
+        write!(f, "if {} {:?}", conditions.join(" && "), self.output)
+    }
+}
+
+/// The file as written, before it is checked.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    #[serde(default)]
+    mode: Mode,
+    #[serde(default, rename = "rule")]
+    rules: Vec<FileRule>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileRule {
+    #[serde(default)]
+    when: Conditions,
+    output: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Conditions {
+    divisible_by: Option<i64>,
+    between: Option<[i64; 2]>,
+    less_than: Option<i64>,
+    greater_than: Option<i64>,
+    equals: Option<i64>,
+}
+
+/// Rules in the order they are tried, and the mode they come with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    pub mode: Mode,
+    pub rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    pub fn load(path: &Path) -> Result<RuleSet, Box<dyn Error>> {
+        let text = fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        RuleSet::parse(&text).map_err(|err| format!("{}: {err}", path.display()).into())
+    }
+
+    pub fn parse(text: &str) -> Result<RuleSet, String> {
+        let file: File = toml::from_str(text).map_err(|err| err.to_string())?;
+        if file.rules.is_empty() {
+            return Err(String::from("there are no [[rule]]s"));
+        }
+
+        let mut rules = Vec::new();
+        for (index, rule) in file.rules.into_iter().enumerate() {
+            let number = index + 1;
+            let Conditions {
+                divisible_by,
+                between,
+                less_than,
+                greater_than,
+                equals,
+            } = rule.when;
+
+            if divisible_by == Some(0) {
+                return Err(format!("rule {number}: nothing is divisible by 0"));
+            }
+            if let Some([low, high]) = between
+                && low > high
+            {
+                return Err(format!("rule {number}: between [{low}, {high}] is empty"));
+            }
+
+            let when = [
+                divisible_by.map(Predicate::DivisibleBy),
+                between.map(|[low, high]| Predicate::Between(low, high)),
+                less_than.map(Predicate::LessThan),
+                greater_than.map(Predicate::GreaterThan),
+                equals.map(Predicate::Equals),
+            ];
+            rules.push(Rule {
+                when: when.into_iter().flatten().collect(),
+                output: rule.output,
+            });
+        }
+
+        Ok(RuleSet {
+            mode: file.mode,
+            rules,
+        })
+    }
+
+    /// The indexes of the rules that produce output for `n`.
+    pub fn fired(&self, n: i64, mode: Mode) -> Vec<usize> {
+        let mut matching = (0..self.rules.len()).filter(|&rule| self.rules[rule].matches(n));
+        match mode {
+            Mode::First => matching.next().into_iter().collect(),
+            Mode::All => matching.collect(),
+        }
+    }
+
+    /// What to print for `n`: the outputs of the rules that fired, run
+    /// together, or the number itself if none did.
+    pub fn output(&self, n: i64, mode: Mode) -> String {
+        let fired = self.fired(n, mode);
+        if fired.is_empty() {
+            return n.to_string();
+        }
+        fired.iter().map(|&rule| self.rules[rule].output(n)).collect()
+    }
+
+    /// Evaluates every number in `range`, writing each one's line to `out`
+    /// as it goes, and keeps count of which rules matched and which fired.
+    ///
+    /// Nothing but the counts is kept, so a range of any size runs in the
+    /// same memory.
+    pub fn run(
+        &self,
+        range: RangeInclusive<i64>,
+        mode: Mode,
+        out: &mut impl Write,
+    ) -> io::Result<Report> {
+        let mut report = Report {
+            matched: vec![0; self.rules.len()],
+            fired: vec![0; self.rules.len()],
+        };
+
+        for n in range {
+            for (rule, matched) in report.matched.iter_mut().enumerate() {
+                if self.rules[rule].matches(n) {
+                    *matched += 1;
+                }
+            }
+            for rule in self.fired(n, mode) {
+                report.fired[rule] += 1;
+            }
+            writeln!(out, "{}", self.output(n, mode))?;
+        }
+
+        Ok(report)
+    }
+}
+
+/// What `RuleSet::run` found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// For each rule, how many numbers it matched. A range can hold more
+    /// numbers than a `u32` counts.
+    pub matched: Vec<u64>,
+    /// For each rule, how many numbers it produced output for. Less than
+    /// `matched` when an earlier rule got there first.
+    pub fired: Vec<u64>,
+}
+
+impl Report {
+    /// Rules that matched some numbers but never fired: an earlier rule
+    /// always got there first, the classic mistake of checking `% 3`
+    /// before `% 15`.
+    pub fn shadowed(&self) -> Vec<usize> {
+        (0..self.matched.len())
+            .filter(|&rule| self.matched[rule] > 0 && self.fired[rule] == 0)
+            .collect()
+    }
+}
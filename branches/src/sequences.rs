@@ -0,0 +1,245 @@
+//! Integer sequences as iterators, to feed the chapter's loops something
+//! more interesting than a counter.
+//!
+//! Every term is a `u64`. The sequences grow fast, so they run out of room
+//! quickly; instead of overflowing, an iterator simply ends with the last
+//! term that fits.
+//!
+//! ```
+//! use branches::sequences;
+//!
+//! let first: Vec<u64> = sequences::fibonacci().take(8).collect();
+//! assert_eq!(first, [0, 1, 1, 2, 3, 5, 8, 13]);
+//!
+//! // `nth` jumps ahead without computing the terms in between.
+//! assert_eq!(sequences::fibonacci().nth(90), Some(2_880_067_194_370_816_120));
+//! assert_eq!(sequences::fibonacci().count(), 94);
+//! ```
+
+use std::iter::FusedIterator;
+
+/// 0, 1, 1, 2, 3, 5, 8, ...: each term is the sum of the two before it.
+pub fn fibonacci() -> Recurrence<2> {
+    Recurrence::new([1, 1], [0, 1])
+}
+
+/// 2, 1, 3, 4, 7, 11, ...: the Fibonacci rule from a different start.
+pub fn lucas() -> Recurrence<2> {
+    Recurrence::new([1, 1], [2, 1])
+}
+
+/// 0, 0, 1, 1, 2, 4, 7, 13, ...: each term is the sum of the three before
+/// it.
+pub fn tribonacci() -> Recurrence<3> {
+    Recurrence::new([1, 1, 1], [0, 0, 1])
+}
+
+/// 0, 1, 2, 5, 12, 29, ...: twice the term before plus the one before that.
+pub fn pell() -> Recurrence<2> {
+    Recurrence::new([1, 2], [0, 1])
+}
+
+/// The Collatz sequence from `start`: halve even numbers, turn odd ones
+/// into 3n + 1, and stop at 1.
+///
+/// Nobody has proved that every start reaches 1, but every one anybody has
+/// tried does. 0 never does, so `collatz(0)` is empty.
+pub fn collatz(start: u64) -> Collatz {
+    Collatz {
+        next: (start != 0).then_some(start),
+    }
+}
+
+/// A sequence where each term is a fixed combination of the `K` terms
+/// before it, like Fibonacci (`K` = 2) or Tribonacci (`K` = 3).
+#[derive(Debug, Clone)]
+pub struct Recurrence<const K: usize> {
+    /// What each of the last `K` terms, oldest first, is multiplied by
+    /// before they are added up into the next one.
+    coefficients: [u64; K],
+    /// The next terms to come out, in order.
+    window: [u64; K],
+    /// How many terms of `window` are real. Once a term overflows, nothing
+    /// more is added and the window drains.
+    len: usize,
+}
+
+type Matrix<const K: usize> = [[u128; K]; K];
+
+impl<const K: usize> Recurrence<K> {
+    fn new(coefficients: [u64; K], first: [u64; K]) -> Recurrence<K> {
+        Recurrence {
+            coefficients,
+            window: first,
+            len: K,
+        }
+    }
+
+    /// The term after the window, if it fits.
+    fn following(&self) -> Option<u64> {
+        self.coefficients
+            .iter()
+            .zip(&self.window)
+            .try_fold(0u64, |sum, (&coefficient, &term)| {
+                sum.checked_add(coefficient.checked_mul(term)?)
+            })
+    }
+
+    /// Moving the window one step is multiplying it by this matrix: each
+    /// row but the last shifts a term down, and the last row adds them up.
+    fn step(&self) -> Matrix<K> {
+        let mut step = [[0; K]; K];
+        for (row, cells) in step.iter_mut().enumerate().take(K - 1) {
+            cells[row + 1] = 1;
+        }
+        for (cell, &coefficient) in step[K - 1].iter_mut().zip(&self.coefficients) {
+            *cell = u128::from(coefficient);
+        }
+        step
+    }
+
+    /// Moves the window `n` steps at once, by multiplying it by the `n`-th
+    /// power of `step`, which takes about log2(n) matrix products.
+    ///
+    /// Returns `None`, leaving the window alone, if the power itself
+    /// overflows: then the caller has to walk.
+    fn jump(&mut self, n: usize) -> Option<()> {
+        let power = power(self.step(), n)?;
+
+        let mut window = [0; K];
+        let mut len = 0;
+        for (row, slot) in power.iter().zip(&mut window) {
+            let term = row
+                .iter()
+                .zip(&self.window)
+                .try_fold(0u128, |sum, (&cell, &term)| {
+                    sum.checked_add(cell.checked_mul(u128::from(term))?)
+                })
+                .and_then(|term| u64::try_from(term).ok());
+            match term {
+                Some(term) => {
+                    *slot = term;
+                    len += 1;
+                }
+                None => break,
+            }
+        }
+
+        self.window = window;
+        self.len = len;
+        Some(())
+    }
+}
+
+impl<const K: usize> Iterator for Recurrence<K> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let term = self.window[0];
+        let following = if self.len == K { self.following() } else { None };
+        self.window.rotate_left(1);
+        match following {
+            Some(following) => self.window[K - 1] = following,
+            None => self.len -= 1,
+        }
+        Some(term)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<u64> {
+        // Near the end there are only a few terms left to walk past.
+        if n > 0 && self.len == K && self.jump(n).is_some() {
+            return self.next();
+        }
+
+        for _ in 0..n {
+            self.next()?;
+        }
+        self.next()
+    }
+}
+
+impl<const K: usize> FusedIterator for Recurrence<K> {}
+
+fn multiply<const K: usize>(a: &Matrix<K>, b: &Matrix<K>) -> Option<Matrix<K>> {
+    let mut product = [[0; K]; K];
+    for (row, cells) in product.iter_mut().enumerate() {
+        for (column, cell) in cells.iter_mut().enumerate() {
+            *cell = (0..K).try_fold(0u128, |sum, i| {
+                sum.checked_add(a[row][i].checked_mul(b[i][column])?)
+            })?;
+        }
+    }
+    Some(product)
+}
+
+/// `base` to the `n`-th power by repeated squaring, or `None` if any
+/// number along the way doesn't fit in a `u128`.
+fn power<const K: usize>(mut base: Matrix<K>, mut n: usize) -> Option<Matrix<K>> {
+    let mut result = [[0; K]; K];
+    for (i, row) in result.iter_mut().enumerate() {
+        row[i] = 1;
+    }
+
+    while n > 0 {
+        if n & 1 == 1 {
+            result = multiply(&result, &base)?;
+        }
+        n >>= 1;
+        // Squaring once more than needed could overflow for nothing.
+        if n > 0 {
+            base = multiply(&base, &base)?;
+        }
+    }
+    Some(result)
+}
+
+/// The iterator returned by [`collatz`].
+#[derive(Debug, Clone)]
+pub struct Collatz {
+    next: Option<u64>,
+}
+
+impl Iterator for Collatz {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let term = self.next?;
+        self.next = if term == 1 {
+            None
+        } else if term.is_multiple_of(2) {
+            Some(term / 2)
+        } else {
+            // 3n + 1, unless that no longer fits.
+            term.checked_mul(3).and_then(|tripled| tripled.checked_add(1))
+        };
+        Some(term)
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<u64> {
+        // A run of halvings is a single shift: skip as many as the number
+        // has trailing zeros in one go.
+        while n > 0 {
+            let term = self.next?;
+            if term == 1 {
+                self.next = None;
+                return None;
+            }
+
+            if term.is_multiple_of(2) {
+                let halvings = (term.trailing_zeros() as usize).min(n);
+                self.next = Some(term >> halvings);
+                n -= halvings;
+            } else {
+                self.next();
+                n -= 1;
+            }
+        }
+        self.next()
+    }
+}
+
+impl FusedIterator for Collatz {}
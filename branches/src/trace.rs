@@ -0,0 +1,373 @@
+//! `branches trace`: the chapter's loops again, this time telling us what
+//! they do as they do it.
+//!
+//! A labeled `break` can leave several loops at once, which is hard to see
+//! from the code alone. Here every loop reports entering, each iteration,
+//! what it prints and how it is left, and the report can be drawn as an
+//! indented timeline or as a Graphviz diagram of which loop each `break`
+//! jumps out of.
+//!
+//! The traced loops are a hand-written copy of the ones in `main.rs`, which
+//! stay as the book prints them. Nothing ties the two together except a
+//! test that the copy prints the chapter's lines, so a change to when a loop
+//! breaks has to be made in both by hand.
+
+use std::collections::BTreeMap;
+
+/// Why a loop stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Reason {
+    /// A `break`, in this loop or in one nested inside it.
+    Break,
+    /// A `while` condition turned false.
+    ConditionFalse,
+    /// A `for` loop ran out of items.
+    IteratorDone,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Enter {
+        name: &'static str,
+    },
+    Iteration {
+        name: &'static str,
+        number: u32,
+    },
+    /// A line the loop body printed.
+    Print(String),
+    Break {
+        /// The loop the `break` is written in.
+        from: &'static str,
+        /// The loop it leaves: `from` itself, or an enclosing loop if the
+        /// `break` names its label.
+        to: &'static str,
+        label: Option<&'static str>,
+        value: Option<String>,
+    },
+    Exit {
+        name: &'static str,
+        reason: Reason,
+    },
+}
+
+/// The events so far, and the loops currently running.
+#[derive(Debug, Default)]
+pub struct Trace {
+    events: Vec<Event>,
+    /// Innermost last, with how many iterations it has started.
+    running: Vec<(&'static str, u32)>,
+}
+
+impl Trace {
+    pub fn new() -> Trace {
+        Trace::default()
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    pub fn enter(&mut self, name: &'static str) {
+        self.events.push(Event::Enter { name });
+        self.running.push((name, 0));
+    }
+
+    /// Call at the top of every pass through the innermost loop's body.
+    pub fn iteration(&mut self) {
+        let (name, number) = self.running.last_mut().expect("iteration outside a loop");
+        *number += 1;
+        self.events.push(Event::Iteration {
+            name,
+            number: *number,
+        });
+    }
+
+    /// Records a line where the loop would have printed it.
+    pub fn print(&mut self, line: String) {
+        self.events.push(Event::Print(line));
+    }
+
+    /// Call just before a `break`. `label` is the one the `break` names,
+    /// if any, and `value` what it passes out of the loop.
+    ///
+    /// Every loop from the innermost out to the one the `break` leaves
+    /// exits with it.
+    pub fn break_out(&mut self, label: Option<&'static str>, value: Option<String>) {
+        let from = self.running.last().expect("break outside a loop").0;
+        let depth = match label {
+            Some(label) => self
+                .running
+                .iter()
+                .rposition(|&(name, _)| name == label)
+                .unwrap_or_else(|| panic!("break to {label}, which isn't running")),
+            None => self.running.len() - 1,
+        };
+        let to = self.running[depth].0;
+
+        self.events.push(Event::Break {
+            from,
+            to,
+            label,
+            value,
+        });
+        while self.running.len() > depth {
+            let (name, _) = self.running.pop().unwrap();
+            self.events.push(Event::Exit {
+                name,
+                reason: Reason::Break,
+            });
+        }
+    }
+
+    /// Call after a `while` or `for` loop that ended by itself.
+    pub fn end(&mut self, reason: Reason) {
+        let (name, _) = self.running.pop().expect("end outside a loop");
+        self.events.push(Event::Exit { name, reason });
+    }
+
+    /// One event per line, indented by how deeply nested it happened.
+    pub fn timeline(&self) -> String {
+        let mut out = String::new();
+        let mut depth = 0;
+        let mut line = |depth: usize, text: String| {
+            out.push_str(&"  ".repeat(depth));
+            out.push_str(&text);
+            out.push('\n');
+        };
+
+        // A loop's iterations are one step in from it, and the body of an
+        // iteration one more.
+        for event in &self.events {
+            match event {
+                Event::Enter { name } => {
+                    line(depth, format!("enter {name}"));
+                    depth += 2;
+                }
+                Event::Iteration { number, .. } => line(depth - 1, format!("iteration {number}")),
+                Event::Print(text) => line(depth, format!("| {text}")),
+                Event::Break {
+                    from,
+                    to,
+                    label,
+                    value,
+                } => {
+                    let mut text = break_code(*label, value.as_deref());
+                    if from != to {
+                        text.push_str(&format!(" (leaves {from} and {to})"));
+                    }
+                    line(depth, text);
+                }
+                Event::Exit { name, reason } => {
+                    depth -= 2;
+                    let why = match reason {
+                        Reason::Break => "after a break",
+                        Reason::ConditionFalse => "the condition is false",
+                        Reason::IteratorDone => "no more items",
+                    };
+                    line(depth, format!("exit {name} ({why})"));
+                }
+            }
+        }
+
+        out
+    }
+
+    /// A Graphviz diagram: every loop is a box drawn inside the loops that
+    /// contain it, and every way out of a loop is an arrow to the point
+    /// after it. Pipe it into `dot -Tsvg` to see it.
+    pub fn dot(&self) -> String {
+        let mut loops: Vec<LoopNode> = Vec::new();
+        let mut exits: BTreeMap<(usize, usize, String), u32> = BTreeMap::new();
+        let mut running: Vec<usize> = Vec::new();
+        let index = |loops: &[LoopNode], name: &str| {
+            loops.iter().position(|node| node.name == name).unwrap()
+        };
+
+        for event in &self.events {
+            match event {
+                Event::Enter { name } => {
+                    if !loops.iter().any(|node| node.name == *name) {
+                        loops.push(LoopNode {
+                            name,
+                            parent: running.last().copied(),
+                            entered: 0,
+                            iterations: 0,
+                        });
+                    }
+                    let node = index(&loops, name);
+                    loops[node].entered += 1;
+                    running.push(node);
+                }
+                Event::Iteration { .. } => loops[*running.last().unwrap()].iterations += 1,
+                Event::Print(_) => {}
+                Event::Break {
+                    from,
+                    to,
+                    label,
+                    value,
+                } => {
+                    let text = break_code(*label, value.as_deref());
+                    let key = (index(&loops, from), index(&loops, to), text);
+                    *exits.entry(key).or_insert(0) += 1;
+                }
+                Event::Exit { reason, .. } => {
+                    let node = running.pop().unwrap();
+                    let text = match reason {
+                        // Drawn from the break itself.
+                        Reason::Break => continue,
+                        Reason::ConditionFalse => "condition false",
+                        Reason::IteratorDone => "no more items",
+                    };
+                    *exits.entry((node, node, String::from(text))).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut out = String::from("digraph control_flow {\n");
+        out.push_str("  compound=true;\n");
+        out.push_str("  node [shape=box];\n");
+        for root in (0..loops.len()).filter(|&node| loops[node].parent.is_none()) {
+            cluster(&loops, root, 1, &mut out);
+        }
+        for ((from, to, text), count) in exits {
+            let text = if count > 1 { format!("{text} (x{count})") } else { text };
+            out.push_str(&format!(
+                "  body{from} -> after{to} [label={}, ltail=cluster{from}];\n",
+                quote(&text)
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// The `break` as it is written in the code, like `break 'counting_up`
+/// or `break 20`.
+fn break_code(label: Option<&str>, value: Option<&str>) -> String {
+    let mut code = String::from("break");
+    for part in [label, value].into_iter().flatten() {
+        code.push(' ');
+        code.push_str(part);
+    }
+    code
+}
+
+struct LoopNode {
+    name: &'static str,
+    parent: Option<usize>,
+    entered: u32,
+    iterations: u32,
+}
+
+/// Draws a loop as a cluster holding its body and the loops nested in it,
+/// followed by the point after it, where its exits lead.
+fn cluster(loops: &[LoopNode], node: usize, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    let LoopNode {
+        name,
+        entered,
+        iterations,
+        ..
+    } = loops[node];
+
+    out.push_str(&format!("{indent}subgraph cluster{node} {{\n"));
+    out.push_str(&format!("{indent}  label={};\n", quote(name)));
+    let times = if entered == 1 { String::from("once") } else { format!("{entered} times") };
+    out.push_str(&format!(
+        "{indent}  body{node} [label={}];\n",
+        quote(&format!("entered {times}\n{iterations} iterations"))
+    ));
+    for child in (0..loops.len()).filter(|&child| loops[child].parent == Some(node)) {
+        cluster(loops, child, depth + 1, out);
+    }
+    out.push_str(&format!("{indent}}}\n"));
+    out.push_str(&format!(
+        "{indent}after{node} [label={}, shape=plaintext];\n",
+        quote(&format!("after {name}"))
+    ));
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+/// Runs the traced copy of the loops in the chapter's `main` and returns
+/// what they did, including the lines they print there. A test checks those
+/// lines against the chapter's golden output, but not where each loop
+/// breaks, so edit both copies together.
+pub fn chapter() -> Trace {
+    let mut trace = Trace::new();
+
+    // loop, passing a value out with `break`
+    let mut cnt = 0;
+    trace.enter("result loop");
+    let result = loop {
+        trace.iteration();
+        cnt += 1;
+        if cnt == 10 {
+            trace.break_out(None, Some((cnt * 2).to_string()));
+            break cnt * 2;
+        }
+    };
+    trace.print(format!("The result is {result}"));
+
+    // the labeled loop, and the one inside it that can break out of both
+    let mut cnt = 0;
+    trace.enter("'counting_up");
+    'counting_up: loop {
+        trace.iteration();
+        trace.print(format!("count: {cnt}"));
+        let mut remaining = 10;
+
+        trace.enter("remaining loop");
+        loop {
+            trace.iteration();
+            trace.print(format!("remaining = {remaining}"));
+            if remaining == 9 {
+                trace.break_out(None, None);
+                break;
+            }
+
+            if cnt == 2 {
+                trace.break_out(Some("'counting_up"), None);
+                break 'counting_up;
+            }
+            remaining -= 1;
+        }
+
+        cnt += 1;
+    }
+    trace.print(format!("End count = {cnt}"));
+
+    // while
+    let mut number = 3;
+    trace.enter("while number != 0");
+    while number != 0 {
+        trace.iteration();
+        trace.print(format!("{number}!"));
+
+        number -= 1;
+    }
+    trace.end(Reason::ConditionFalse);
+    trace.print(String::from("LIFTOFF!!!"));
+
+    // for
+    let a = [1, 2, 3, 4, 5];
+    trace.enter("for elem in a");
+    for elem in a {
+        trace.iteration();
+        trace.print(format!("the value is: {elem}"));
+    }
+    trace.end(Reason::IteratorDone);
+
+    trace.enter("for num in (1..4).rev()");
+    for num in (1..4).rev() {
+        trace.iteration();
+        trace.print(format!("{num}"));
+    }
+    trace.end(Reason::IteratorDone);
+    trace.print(String::from("LIFTOFF!!!"));
+
+    trace
+}
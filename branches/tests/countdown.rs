@@ -0,0 +1,76 @@
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use branches::countdown::{self, Timer};
+
+#[test]
+fn durations_take_units() {
+    let parse = |text| countdown::parse_duration(text).map(|duration| duration.as_secs());
+
+    assert_eq!(parse("90"), Ok(90));
+    assert_eq!(parse("90s"), Ok(90));
+    assert_eq!(parse("5m"), Ok(300));
+    assert_eq!(parse("1h30m"), Ok(5400));
+    assert_eq!(parse("1m30s"), Ok(90));
+
+    assert_eq!(parse("99h"), Ok(99 * 60 * 60));
+
+    for bad in [
+        "",
+        "m",
+        "5x",
+        "5m30",
+        "-5s",
+        "1.5m",
+        "99h1s",
+        "5000000000000h",
+        "18446744073709551615",
+    ] {
+        assert!(parse(bad).is_err(), "'{bad}' was accepted");
+    }
+}
+
+#[test]
+fn partial_seconds_round_up() {
+    assert_eq!(countdown::format(Duration::from_secs(90)), "01:30");
+    assert_eq!(countdown::format(Duration::from_millis(89_001)), "01:30");
+    assert_eq!(countdown::format(Duration::from_millis(1)), "00:01");
+    assert_eq!(countdown::format(Duration::ZERO), "00:00");
+    assert_eq!(countdown::format(Duration::from_secs(3725)), "1:02:05");
+
+    assert_eq!(countdown::until_next_tick(Duration::from_millis(89_250)), Duration::from_millis(250));
+    assert_eq!(countdown::until_next_tick(Duration::from_secs(90)), Duration::from_secs(1));
+}
+
+#[test]
+fn pausing_stops_the_clock() {
+    let start = Instant::now();
+    let at = |seconds| start + Duration::from_secs(seconds);
+    let mut timer = Timer::start(Duration::from_secs(60), start);
+
+    assert_eq!(timer.remaining(at(10)), Duration::from_secs(50));
+
+    timer.toggle(at(10));
+    assert!(timer.is_paused());
+    assert_eq!(timer.remaining(at(40)), Duration::from_secs(50));
+
+    timer.toggle(at(40));
+    assert!(!timer.is_paused());
+    assert_eq!(timer.remaining(at(70)), Duration::from_secs(20));
+    assert_eq!(timer.remaining(at(100)), Duration::ZERO);
+}
+
+#[test]
+fn the_command_runs_at_zero() {
+    // Without a terminal the countdown prints one line a second.
+    let output = Command::new(env!("CARGO_BIN_EXE_branches"))
+        .args(["countdown", "1s", "--", "echo", "standup is over"])
+        .output()
+        .expect("the binary runs");
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "00:01\n00:00\nLIFTOFF!!!\nstandup is over\n"
+    );
+}
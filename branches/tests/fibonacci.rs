@@ -0,0 +1,44 @@
+use branches::fibonacci::{self, MAX_U64_N, MAX_U128_N};
+
+#[test]
+fn every_version_agrees() {
+    for n in 0..=30 {
+        assert_eq!(fibonacci::iterative(n), u128::from(fibonacci::recursive(n)), "F({n})");
+    }
+
+    for n in 0..=MAX_U128_N {
+        let expected = fibonacci::iterative(n);
+        assert_eq!(fibonacci::memoized(n), expected, "memoized F({n})");
+        assert_eq!(fibonacci::matrix(n), expected, "matrix F({n})");
+        assert_eq!(fibonacci::fast_doubling(n), expected, "fast doubling F({n})");
+        assert_eq!(fibonacci::checked_u128(n), Some(expected), "checked F({n})");
+        assert_eq!(fibonacci::big(n).to_string(), expected.to_string(), "big F({n})");
+    }
+}
+
+#[test]
+fn checked_versions_stop_where_the_type_does() {
+    assert_eq!(fibonacci::checked(MAX_U64_N), Some(12_200_160_415_121_876_738));
+    assert_eq!(fibonacci::checked(MAX_U64_N + 1), None);
+
+    assert_eq!(
+        fibonacci::checked_u128(MAX_U128_N),
+        Some(332_825_110_087_067_562_321_196_029_789_634_457_848)
+    );
+    assert_eq!(fibonacci::checked_u128(MAX_U128_N + 1), None);
+}
+
+#[test]
+fn big_computes_f_10000_exactly() {
+    let digits = fibonacci::big(10_000).to_string();
+
+    assert_eq!(digits.len(), 2090);
+    assert!(digits.starts_with("336447648764317832666216120051075"));
+    assert!(digits.ends_with("366875"));
+}
+
+#[test]
+#[should_panic(expected = "doesn't fit in a u128")]
+fn fast_versions_refuse_what_does_not_fit() {
+    fibonacci::fast_doubling(MAX_U128_N + 1);
+}
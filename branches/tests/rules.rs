@@ -0,0 +1,105 @@
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+use branches::rules::{Mode, Predicate, Report, RuleSet};
+
+const SWAPPED: &str = r#"
+[[rule]]
+when = { divisible_by = 3 }
+output = "Fizz"
+
+[[rule]]
+when = { divisible_by = 15 }
+output = "FizzBuzz"
+
+[[rule]]
+when = { divisible_by = 5 }
+output = "Buzz"
+"#;
+
+/// Runs `rules` and returns the lines it printed along with the report.
+fn run(rules: &RuleSet, range: RangeInclusive<i64>, mode: Mode) -> (Vec<String>, Report) {
+    let mut out = Vec::new();
+    let report = rules.run(range, mode, &mut out).unwrap();
+    let lines = String::from_utf8(out).unwrap().lines().map(String::from).collect();
+    (lines, report)
+}
+
+#[test]
+fn the_example_plays_fizzbuzz() {
+    let rules = RuleSet::load(Path::new("rules/fizzbuzz.toml")).unwrap();
+
+    assert_eq!(rules.mode, Mode::First);
+    let (lines, report) = run(&rules, 9..=15, rules.mode);
+    assert_eq!(lines, ["Fizz", "Buzz", "11", "Fizz", "13", "14", "FizzBuzz"]);
+    assert!(report.shadowed().is_empty());
+}
+
+#[test]
+fn order_matters_only_for_the_first_match() {
+    let rules = RuleSet::parse(SWAPPED).unwrap();
+
+    // Checking % 3 first hides the FizzBuzz rule completely...
+    let (lines, first) = run(&rules, 1..=30, Mode::First);
+    assert_eq!(lines[14], "Fizz");
+    assert_eq!(first.matched[1], 2);
+    assert_eq!(first.shadowed(), [1]);
+
+    // ...while with every match printing, each rule gets its say.
+    let (lines, all) = run(&rules, 1..=30, Mode::All);
+    assert_eq!(lines[14], "FizzFizzBuzzBuzz");
+    assert!(all.shadowed().is_empty());
+}
+
+#[test]
+fn conditions_combine_and_outputs_name_the_number() {
+    let rules = RuleSet::parse(
+        r#"
+        mode = "all"
+
+        [[rule]]
+        when = { between = [-5, 5], divisible_by = 2, greater_than = -3 }
+        output = "<{n} is small and even>"
+
+        [[rule]]
+        output = "."
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(rules.mode, Mode::All);
+    assert_eq!(
+        rules.rules[0].when,
+        [
+            Predicate::DivisibleBy(2),
+            Predicate::Between(-5, 5),
+            Predicate::GreaterThan(-3)
+        ]
+    );
+    assert_eq!(
+        run(&rules, -4..=2, Mode::All).0,
+        [
+            ".",
+            ".",
+            "<-2 is small and even>.",
+            ".",
+            "<0 is small and even>.",
+            ".",
+            "<2 is small and even>.",
+        ]
+    );
+}
+
+#[test]
+fn bad_rules_are_refused() {
+    for bad in [
+        "",
+        "[[rule]]\nwhen = { divisible_by = 0 }\noutput = \"x\"",
+        "[[rule]]\nwhen = { between = [5, 1] }\noutput = \"x\"",
+        "[[rule]]\nwhen = { divisble_by = 3 }\noutput = \"x\"",
+        "[[rule]]\nwhen = { equals = 3 }",
+        "mode = \"some\"\n[[rule]]\noutput = \"x\"",
+    ] {
+        assert!(RuleSet::parse(bad).is_err(), "accepted {bad:?}");
+    }
+}
@@ -0,0 +1,69 @@
+use branches::sequences;
+
+/// `nth` from every position, for every distance up to past the end, must
+/// land where calling `next` that many times would.
+fn check_nth<I: Iterator<Item = u64> + Clone>(sequence: I) {
+    let all: Vec<u64> = sequence.clone().collect();
+
+    for start in 0..=all.len() {
+        for n in 0..=all.len() + 2 {
+            let mut jumped = sequence.clone();
+            if start > 0 {
+                jumped.nth(start - 1);
+            }
+
+            assert_eq!(jumped.nth(n), all.get(start + n).copied(), "nth({n}) from {start}");
+            assert_eq!(
+                jumped.collect::<Vec<_>>(),
+                all.get(start + n + 1..).unwrap_or_default(),
+                "after nth({n}) from {start}"
+            );
+        }
+    }
+
+    assert_eq!(sequence.clone().nth(usize::MAX), None);
+}
+
+#[test]
+fn sequences_start_as_expected() {
+    let first = |sequence: &mut dyn Iterator<Item = u64>| sequence.take(8).collect::<Vec<_>>();
+
+    assert_eq!(first(&mut sequences::fibonacci()), [0, 1, 1, 2, 3, 5, 8, 13]);
+    assert_eq!(first(&mut sequences::lucas()), [2, 1, 3, 4, 7, 11, 18, 29]);
+    assert_eq!(first(&mut sequences::tribonacci()), [0, 0, 1, 1, 2, 4, 7, 13]);
+    assert_eq!(first(&mut sequences::pell()), [0, 1, 2, 5, 12, 29, 70, 169]);
+    assert_eq!(
+        sequences::collatz(6).collect::<Vec<_>>(),
+        [6, 3, 10, 5, 16, 8, 4, 2, 1]
+    );
+    assert_eq!(sequences::collatz(0).next(), None);
+}
+
+#[test]
+fn sequences_end_at_the_last_term_that_fits() {
+    assert_eq!(sequences::fibonacci().last(), Some(12_200_160_415_121_876_738));
+    assert_eq!(sequences::fibonacci().count(), 94);
+
+    for last in [
+        sequences::lucas().last(),
+        sequences::tribonacci().last(),
+        sequences::pell().last(),
+    ] {
+        let last = last.unwrap();
+        assert!(last > u64::MAX / 4, "{last} is not near the end of a u64");
+    }
+
+    // 3n + 1 doesn't fit, so the sequence stops at the odd number.
+    let odd = (u64::MAX / 3 + 1) | 1;
+    assert_eq!(sequences::collatz(odd).collect::<Vec<_>>(), [odd]);
+}
+
+#[test]
+fn nth_agrees_with_walking() {
+    check_nth(sequences::fibonacci());
+    check_nth(sequences::lucas());
+    check_nth(sequences::tribonacci());
+    check_nth(sequences::pell());
+    check_nth(sequences::collatz(27));
+    check_nth(sequences::collatz(1 << 40));
+}
@@ -0,0 +1,81 @@
+use branches::trace::{self, Event, Reason};
+
+#[test]
+fn the_labeled_break_leaves_both_loops() {
+    let trace = trace::chapter();
+    let events = trace.events();
+
+    let labeled = events
+        .iter()
+        .position(|event| matches!(event, Event::Break { label: Some(_), .. }))
+        .expect("the labeled break was traced");
+    assert_eq!(
+        events[labeled..labeled + 3],
+        [
+            Event::Break {
+                from: "remaining loop",
+                to: "'counting_up",
+                label: Some("'counting_up"),
+                value: None,
+            },
+            Event::Exit {
+                name: "remaining loop",
+                reason: Reason::Break,
+            },
+            Event::Exit {
+                name: "'counting_up",
+                reason: Reason::Break,
+            },
+        ]
+    );
+    assert_eq!(events[labeled + 3], Event::Print(String::from("End count = 2")));
+
+    assert!(trace.timeline().contains(
+        "\
+    enter remaining loop
+      iteration 1
+        | remaining = 10
+        break 'counting_up (leaves remaining loop and 'counting_up)
+    exit remaining loop (after a break)
+exit 'counting_up (after a break)
+"
+    ));
+}
+
+#[test]
+fn the_diagram_draws_each_way_out() {
+    let dot = trace::chapter().dot();
+
+    assert!(dot.starts_with("digraph control_flow {\n"));
+    assert!(dot.contains("  body0 -> after0 [label=\"break 20\", ltail=cluster0];\n"));
+    assert!(dot.contains("  body2 -> after1 [label=\"break 'counting_up\", ltail=cluster2];\n"));
+    assert!(dot.contains("  body2 -> after2 [label=\"break (x2)\", ltail=cluster2];\n"));
+    assert!(dot.contains("  body3 -> after3 [label=\"condition false\", ltail=cluster3];\n"));
+}
+
+/// `chapter` is a copy of the loops in `main`. The golden file holds what
+/// `main` prints, so the copy can't print anything `main` doesn't; which
+/// `break` ends each loop is not checked.
+#[test]
+fn the_trace_prints_what_the_chapter_prints() {
+    let golden = concat!(env!("CARGO_MANIFEST_DIR"), "/../snapshots/golden/branches.stdout");
+    let golden = std::fs::read_to_string(golden).expect("the golden output is there");
+    let golden: Vec<&str> = golden.lines().collect();
+
+    let trace = trace::chapter();
+    let printed: Vec<&str> = trace
+        .events()
+        .iter()
+        .filter_map(|event| match event {
+            Event::Print(line) => Some(line.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    assert!(!printed.is_empty());
+    assert!(
+        golden.windows(printed.len()).any(|lines| lines == printed),
+        "the traced loops print\n{}\nbut that isn't in branches.stdout",
+        printed.join("\n")
+    );
+}
@@ -0,0 +1,220 @@
+# Messages shown to the player, in English. Every other catalog falls back
+# to this one for anything it leaves out.
+
+## The number game
+
+guess-the-number = Guess the number!
+intro = I'm thinking of a number between { $min } and { $max }. You have { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    }.
+tolerance-note = Anything within { $tolerance } of it counts.
+input-guess = Please input your guess!
+you-guessed = You guessed: { $guess }
+too-small = Too small!
+too-big = Too big!
+you-win = You win!
+you-win-close = You win! The number was { $secret }.
+you-won-in = You won in { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    }!
+out-of-range = Your guess must be between { $min } and { $max }.
+hint-cost = That cost an attempt: { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    } and { $hints ->
+        [one] { $hints } hint
+       *[other] { $hints } hints
+    } left.
+gave-up = You gave up! The number was { $secret }.
+no-more-input = No more input. The number was { $secret }.
+you-lose = You lose! The number was { $secret }.
+
+## Blitz
+
+blitz-total = You have { $seconds ->
+        [one] { $seconds } second
+       *[other] { $seconds } seconds
+    } for the whole game.
+blitz-per-guess = Each guess must come within { $seconds ->
+        [one] { $seconds } second
+       *[other] { $seconds } seconds
+    }, or the attempt is lost.
+blitz-time-left = ({ $seconds ->
+        [one] { $seconds } second
+       *[other] { $seconds } seconds
+    } left)
+blitz-guess-time-left = ({ $seconds ->
+        [one] { $seconds } second
+       *[other] { $seconds } seconds
+    } for this guess)
+timed-out = Too slow! That attempt is lost; { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    } left.
+time-up = Time's up!
+blitz-score = Your score: { $points } ({ $attempts } for the attempts, { $penalty } for the time and hints). Lower is better.
+
+## Help
+
+help-intro = Type a number to guess it, or one of these commands:
+help-hint = Show the range the secret can still be in
+help-parity = Tell whether the secret is even or odd *
+help-div = Tell whether the secret is divisible by N (3 if left out) *
+help-near = Tell whether the secret is within { $distance } of your last guess *
+help-help = Show this help
+help-quit = Give up and reveal the secret
+help-paid-note = * Costs an attempt and one of your hints (see --hints).
+help-word-intro = Type a word to guess it, or one of these commands:
+help-word-hint = Show the words the secret is between
+help-word-quit = Give up and reveal the secret
+
+## Hints
+
+hint-range = The number is between { $low } and { $high }.
+hint-even = The number is even.
+hint-odd = The number is odd.
+hint-divisible = The number is divisible by { $divisor }.
+hint-not-divisible = The number is not divisible by { $divisor }.
+hint-near = The number is within { $distance } of your last guess ({ $guess }).
+hint-far = The number is more than { $distance } away from your last guess ({ $guess }).
+hint-disabled = Hints are off. Start the game with --hints <N> to get some.
+hint-none-left = You have no hints left.
+hint-no-attempt-to-spare = A hint costs an attempt, and you only have one left.
+hint-no-guess-yet = Make a guess first, then ask if you're near.
+hint-bad-divisor = Pick a divisor of at least 2.
+game-over = The game is over.
+
+## Input the game didn't understand
+
+input-empty = Please type a number (or 'help').
+input-not-a-number = '{ $input }' is not a number. Type 'help' for the commands.
+input-out-of-range = '{ $input }' doesn't fit in a u32 ({ $min } to { $max }).
+input-unknown-hint = '{ $input }' is not a hint. Try 'hint', 'hint parity', 'hint div 3' or 'hint near'.
+input-unparsable = '{ $input }' is not a valid guess: { $reason }.
+input-not-one-word = '{ $input }' is more than one word. Type a single word.
+reason-invalid = not a number
+reason-too-precise = more than { $digits ->
+        [one] { $digits } digit
+       *[other] { $digits } digits
+    } after the decimal point
+reason-too-large = too large
+reason-too-small = too small
+
+## The word game
+
+guess-the-word = Guess the word!
+words-intro = I'm thinking of one of { $count } words. You have { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    }.
+word-after = The word comes after { $word }.
+word-before = The word comes before { $word }.
+word-between = The word comes after { $after } and before { $before }.
+word-win = You win! The word was { $secret }.
+make-guess-first = Make a guess first.
+word-gave-up = You gave up! The word was { $secret }.
+word-no-more-input = No more input. The word was { $secret }.
+word-lose = You lose! The word was { $secret }.
+
+## Reverse mode
+
+reverse-intro = Think of a number between { $min } and { $max }, and I'll guess it!
+reverse-ask = Is it { $guess }? Type higher, lower or correct.
+reverse-not-an-answer = '{ $input }' is not an answer. Type higher, lower or correct.
+reverse-got-it = I got it in { $guesses ->
+        [one] { $guesses } guess
+       *[other] { $guesses } guesses
+    }!
+reverse-cheating = { $reason }. That's cheating!
+reverse-no-more-answers = No more answers. Goodbye!
+contradiction-between = You said lower than { $lower } but higher than { $higher }
+contradiction-above-max = You said higher than { $higher }, but the number is at most { $max }
+contradiction-below-min = You said lower than { $lower }, but the number is at least { $min }
+
+## Playing over the network
+
+serve-listening = Serving the guessing game on { $addr }.
+serve-connect = Players can connect with `guessing_game join <host>` or `nc <host> { $port }`.
+server-welcome = Welcome to the guessing game! What's your name?
+server-anonymous = anonymous
+server-round-intro = Round { $round }: I'm thinking of a number between { $min } and { $max }. You have { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    }.
+server-joined = { $name } joined the game.
+server-left = { $name } left the game.
+server-too-small = { $name } guessed { $guess }: too small!
+server-too-big = { $name } guessed { $guess }: too big!
+server-correct = { $name } guessed { $guess }: correct! { $name } wins round { $round }!
+server-out-of-attempts = You are out of attempts. Wait for the next round.
+server-nobody = Nobody found it. The number was { $secret }.
+
+## Simulations and replays
+
+simulate-intro = { $strategy } strategy, { $games ->
+        [one] { $games } game
+       *[other] { $games } games
+    } between { $min } and { $max } with { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    } each:
+simulate-won = won:
+simulate-average = average attempts:
+simulate-max = max attempts:
+simulate-bound = binary search needs at most { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    } for this range.
+replay-header = Session with seed { $seed } between { $min } and { $max } ({ $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    }), { $entries ->
+        [one] { $entries } entry
+       *[other] { $entries } entries
+    }.
+replay-all-match = Every outcome matches the log.
+replay-end-of-input = end of input
+replay-mismatch = Entry { $index } ({ $input }): the log says { $recorded }, the replay gives { $replayed }
+replay-differ = { $differ } of { $entries ->
+        [one] { $entries } outcome differs
+       *[other] { $entries } outcomes differ
+    }.
+
+## Watching a bot play
+
+watch-intro = The { $bot } bot is guessing a number between { $min } and { $max }.
+watch-guess = Bot guessed: { $guess } { $answer }
+watch-correct = Correct!
+watch-out-of-range = Out of range!
+watch-game-over = Game over!
+watch-won = The bot won in { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    }.
+watch-lost = The bot lost. The number was { $secret }.
+
+## Terminal UI
+
+tui-prompt = Type a guess and press Enter. Esc gives up.
+tui-title = Guess the number between { $min } and { $max }!
+tui-too-small = { $guess } is too small!
+tui-too-big = { $guess } is too big!
+tui-correct = { $guess } is correct!
+tui-help = Type a number, 'hint' for the range, 'hint parity|div N|near' for a paid hint, or Esc to give up.
+tui-win = You win in { $attempts ->
+        [one] { $attempts } attempt
+       *[other] { $attempts } attempts
+    }! Press any key to leave.
+tui-lose = You lose! The number was { $secret }. Press any key to leave.
+tui-still-possible = Still possible
+tui-guesses = Guesses
+tui-status = Status
+tui-guess = Guess
+tui-answer-too-small = too small
+tui-answer-too-big = too big
+tui-answer-correct = correct
+tui-attempts = Attempts: { $attempts } / { $max }
+tui-hints = Hints: { $hints } left
+tui-time = Time: { $time }
@@ -0,0 +1,160 @@
+# Messages shown to the player, in Vietnamese. Anything missing here is
+# shown in English.
+#
+# Vietnamese nouns don't change with the count ("1 lượt", "5 lượt"), so
+# unlike en.ftl these messages need no plural variants.
+
+## The number game
+
+guess-the-number = Hãy đoán số!
+intro = Tôi đang nghĩ một số từ { $min } đến { $max }. Bạn có { $attempts } lượt đoán.
+tolerance-note = Sai lệch không quá { $tolerance } vẫn được tính là đúng.
+input-guess = Mời bạn nhập lần đoán!
+you-guessed = Bạn đoán: { $guess }
+too-small = Nhỏ quá!
+too-big = Lớn quá!
+you-win = Bạn thắng rồi!
+you-win-close = Bạn thắng rồi! Số cần tìm là { $secret }.
+you-won-in = Bạn thắng sau { $attempts } lượt đoán!
+out-of-range = Số đoán phải nằm trong khoảng từ { $min } đến { $max }.
+hint-cost = Gợi ý vừa tốn một lượt: còn { $attempts } lượt đoán và { $hints } gợi ý.
+gave-up = Bạn đã bỏ cuộc! Số cần tìm là { $secret }.
+no-more-input = Hết dữ liệu nhập. Số cần tìm là { $secret }.
+you-lose = Bạn thua rồi! Số cần tìm là { $secret }.
+
+## Blitz
+
+blitz-total = Bạn có { $seconds } giây cho cả trò chơi.
+blitz-per-guess = Mỗi lần đoán phải xong trong { $seconds } giây, nếu không sẽ mất lượt.
+blitz-time-left = (còn { $seconds } giây)
+blitz-guess-time-left = (còn { $seconds } giây cho lần đoán này)
+timed-out = Chậm quá! Bạn mất lượt này; còn { $attempts } lượt đoán.
+time-up = Hết giờ!
+blitz-score = Điểm của bạn: { $points } ({ $attempts } cho số lượt đoán, { $penalty } cho thời gian và gợi ý). Càng thấp càng tốt.
+
+## Help
+
+help-intro = Nhập một số để đoán, hoặc một trong các lệnh sau:
+help-hint = Xem khoảng mà số bí mật vẫn có thể nằm trong
+help-parity = Cho biết số bí mật là chẵn hay lẻ *
+help-div = Cho biết số bí mật có chia hết cho N không (mặc định là 3) *
+help-near = Cho biết số bí mật có cách lần đoán trước không quá { $distance } không *
+help-help = Hiện hướng dẫn này
+help-quit = Bỏ cuộc và tiết lộ số bí mật
+help-paid-note = * Tốn một lượt đoán và một gợi ý của bạn (xem --hints).
+help-word-intro = Nhập một từ để đoán, hoặc một trong các lệnh sau:
+help-word-hint = Xem từ bí mật nằm giữa hai từ nào
+help-word-quit = Bỏ cuộc và tiết lộ từ bí mật
+
+## Hints
+
+hint-range = Số cần tìm nằm trong khoảng từ { $low } đến { $high }.
+hint-even = Số cần tìm là số chẵn.
+hint-odd = Số cần tìm là số lẻ.
+hint-divisible = Số cần tìm chia hết cho { $divisor }.
+hint-not-divisible = Số cần tìm không chia hết cho { $divisor }.
+hint-near = Số cần tìm cách lần đoán trước ({ $guess }) không quá { $distance }.
+hint-far = Số cần tìm cách lần đoán trước ({ $guess }) hơn { $distance }.
+hint-disabled = Gợi ý đang tắt. Hãy bắt đầu trò chơi với --hints <N> để có gợi ý.
+hint-none-left = Bạn đã hết gợi ý.
+hint-no-attempt-to-spare = Mỗi gợi ý tốn một lượt đoán, mà bạn chỉ còn một lượt.
+hint-no-guess-yet = Hãy đoán một lần trước, rồi mới hỏi có gần không.
+hint-bad-divisor = Hãy chọn số chia từ 2 trở lên.
+game-over = Trò chơi đã kết thúc.
+
+## Input the game didn't understand
+
+input-empty = Vui lòng nhập một số (hoặc 'help').
+input-not-a-number = '{ $input }' không phải là số. Gõ 'help' để xem các lệnh.
+input-out-of-range = '{ $input }' không nằm trong phạm vi của u32 (từ { $min } đến { $max }).
+input-unknown-hint = '{ $input }' không phải là gợi ý. Hãy thử 'hint', 'hint parity', 'hint div 3' hoặc 'hint near'.
+input-unparsable = '{ $input }' không phải là số đoán hợp lệ: { $reason }.
+input-not-one-word = '{ $input }' có nhiều hơn một từ. Chỉ nhập một từ thôi.
+reason-invalid = không phải là số
+reason-too-precise = có hơn { $digits } chữ số sau dấu thập phân
+reason-too-large = quá lớn
+reason-too-small = quá nhỏ
+
+## The word game
+
+guess-the-word = Hãy đoán từ!
+words-intro = Tôi đang nghĩ một trong { $count } từ. Bạn có { $attempts } lượt đoán.
+word-after = Từ cần tìm đứng sau { $word }.
+word-before = Từ cần tìm đứng trước { $word }.
+word-between = Từ cần tìm đứng sau { $after } và trước { $before }.
+word-win = Bạn thắng rồi! Từ cần tìm là { $secret }.
+make-guess-first = Hãy đoán một lần trước đã.
+word-gave-up = Bạn đã bỏ cuộc! Từ cần tìm là { $secret }.
+word-no-more-input = Hết dữ liệu nhập. Từ cần tìm là { $secret }.
+word-lose = Bạn thua rồi! Từ cần tìm là { $secret }.
+
+## Reverse mode
+
+reverse-intro = Hãy nghĩ một số từ { $min } đến { $max }, tôi sẽ đoán ra!
+reverse-ask = Có phải { $guess } không? Gõ higher (cao hơn), lower (thấp hơn) hoặc correct (đúng).
+reverse-not-an-answer = '{ $input }' không phải là câu trả lời. Gõ higher, lower hoặc correct.
+reverse-got-it = Tôi đoán ra sau { $guesses } lần!
+reverse-cheating = { $reason }. Bạn ăn gian rồi!
+reverse-no-more-answers = Hết câu trả lời. Tạm biệt!
+contradiction-between = Bạn nói thấp hơn { $lower } nhưng lại cao hơn { $higher }
+contradiction-above-max = Bạn nói cao hơn { $higher }, nhưng số đó lớn nhất là { $max }
+contradiction-below-min = Bạn nói thấp hơn { $lower }, nhưng số đó nhỏ nhất là { $min }
+
+## Playing over the network
+
+serve-listening = Trò chơi đoán số đang chạy tại { $addr }.
+serve-connect = Người chơi có thể vào bằng `guessing_game join <host>` hoặc `nc <host> { $port }`.
+server-welcome = Chào mừng đến với trò chơi đoán số! Bạn tên là gì?
+server-anonymous = ẩn danh
+server-round-intro = Vòng { $round }: Tôi đang nghĩ một số từ { $min } đến { $max }. Bạn có { $attempts } lượt đoán.
+server-joined = { $name } đã vào chơi.
+server-left = { $name } đã rời cuộc chơi.
+server-too-small = { $name } đoán { $guess }: nhỏ quá!
+server-too-big = { $name } đoán { $guess }: lớn quá!
+server-correct = { $name } đoán { $guess }: đúng rồi! { $name } thắng vòng { $round }!
+server-out-of-attempts = Bạn đã hết lượt đoán. Hãy chờ vòng sau.
+server-nobody = Không ai tìm ra. Số cần tìm là { $secret }.
+
+## Simulations and replays
+
+simulate-intro = Chiến lược { $strategy }, { $games } ván từ { $min } đến { $max }, mỗi ván { $attempts } lượt đoán:
+simulate-won = thắng:
+simulate-average = số lượt trung bình:
+simulate-max = số lượt nhiều nhất:
+simulate-bound = tìm kiếm nhị phân cần nhiều nhất { $attempts } lượt cho khoảng này.
+replay-header = Phiên chơi với seed { $seed } từ { $min } đến { $max } ({ $attempts } lượt đoán), { $entries } mục.
+replay-all-match = Mọi kết quả đều khớp với nhật ký.
+replay-end-of-input = hết dữ liệu nhập
+replay-mismatch = Mục { $index } ({ $input }): nhật ký ghi { $recorded }, chạy lại thì được { $replayed }
+replay-differ = { $differ } trong { $entries } kết quả không khớp.
+
+## Watching a bot play
+
+watch-intro = Máy ({ $bot }) đang đoán một số từ { $min } đến { $max }.
+watch-guess = Máy đoán: { $guess } { $answer }
+watch-correct = Đúng rồi!
+watch-out-of-range = Ngoài khoảng!
+watch-game-over = Hết lượt!
+watch-won = Máy thắng sau { $attempts } lượt đoán.
+watch-lost = Máy thua rồi. Số cần tìm là { $secret }.
+
+## Terminal UI
+
+tui-prompt = Nhập số đoán rồi nhấn Enter. Nhấn Esc để bỏ cuộc.
+tui-title = Đoán số từ { $min } đến { $max }!
+tui-too-small = { $guess } nhỏ quá!
+tui-too-big = { $guess } lớn quá!
+tui-correct = { $guess } đúng rồi!
+tui-help = Nhập một số, 'hint' để xem khoảng, 'hint parity|div N|near' để mua gợi ý, hoặc Esc để bỏ cuộc.
+tui-win = Bạn thắng sau { $attempts } lượt! Nhấn phím bất kỳ để thoát.
+tui-lose = Bạn thua rồi! Số cần tìm là { $secret }. Nhấn phím bất kỳ để thoát.
+tui-still-possible = Vẫn có thể
+tui-guesses = Các lần đoán
+tui-status = Trạng thái
+tui-guess = Số đoán
+tui-answer-too-small = nhỏ quá
+tui-answer-too-big = lớn quá
+tui-answer-correct = đúng
+tui-attempts = Lượt đoán: { $attempts } / { $max }
+tui-hints = Gợi ý: còn { $hints }
+tui-time = Thời gian: { $time }
@@ -0,0 +1,111 @@
+//! Playing against the clock.
+//!
+//! `read_line` blocks until the player presses Enter, so it can't notice
+//! that time ran out. In blitz mode a thread does the blocking reads and
+//! hands the lines over a channel, and the game waits on the channel with
+//! `recv_timeout` instead.
+
+use std::io::{self, BufRead};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The whole game's budget when only `--blitz` is given.
+pub const DEFAULT_TOTAL: Duration = Duration::from_secs(60);
+
+/// The time for each guess when only `--blitz` is given.
+pub const DEFAULT_PER_GUESS: Duration = Duration::from_secs(10);
+
+/// A blitz score gets one extra point for every this long the game took.
+pub const TIME_PER_POINT: Duration = Duration::from_secs(5);
+
+/// How long the player has. Either limit may be left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub total: Option<Duration>,
+    pub per_guess: Option<Duration>,
+}
+
+/// What waiting for the next line ended with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Line(String),
+    /// The time for this guess ran out.
+    TimedOut,
+    /// The time for the whole game ran out.
+    OutOfTime,
+    /// Standard input was closed.
+    Closed,
+}
+
+/// Standard input, read on a thread of its own so it can be waited on with
+/// a timeout.
+///
+/// The thread stays blocked in `read_line` after the game is over; it goes
+/// away when the program exits.
+pub struct Clock {
+    limits: Limits,
+    started: Instant,
+    lines: Receiver<String>,
+}
+
+impl Clock {
+    /// Starts the clock and the thread reading standard input.
+    pub fn start(limits: Limits) -> Clock {
+        let (sender, lines) = mpsc::channel();
+
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                // Stop once the game has stopped listening.
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+            // Dropping the sender tells the game the input is closed.
+        });
+
+        Clock {
+            limits,
+            started: Instant::now(),
+            lines,
+        }
+    }
+
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// What is left of the whole game's budget, if it has one.
+    pub fn time_left(&self) -> Option<Duration> {
+        let total = self.limits.total?;
+        Some(total.saturating_sub(self.elapsed()))
+    }
+
+    /// Waits for the next line, for at most the time a guess is allowed and
+    /// what is left of the game's budget, whichever runs out first.
+    pub fn next_line(&self) -> Input {
+        let time_left = self.time_left();
+        let wait = match (time_left, self.limits.per_guess) {
+            (Some(total), Some(per_guess)) => Some(total.min(per_guess)),
+            (total, per_guess) => total.or(per_guess),
+        };
+
+        let result = match wait {
+            Some(wait) => self.lines.recv_timeout(wait),
+            None => self.lines.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match result {
+            Ok(line) => Input::Line(line),
+            Err(RecvTimeoutError::Disconnected) => Input::Closed,
+            // Whichever limit was the shorter one is the one that ran out.
+            Err(RecvTimeoutError::Timeout) if wait == time_left => Input::OutOfTime,
+            Err(RecvTimeoutError::Timeout) => Input::TimedOut,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::io::{self, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::thread;
+
+/// Connects to a `serve`-ing game and relays lines both ways until either
+/// side hangs up.
+pub fn join(addr: impl ToSocketAddrs) -> io::Result<()> {
+    let stream = TcpStream::connect(addr)?;
+    let mut from_server = stream.try_clone()?;
+
+    let printer = thread::spawn(move || {
+        let mut stdout = io::stdout();
+        let _ = io::copy(&mut from_server, &mut stdout);
+        let _ = stdout.flush();
+    });
+
+    let mut to_server = &stream;
+    io::copy(&mut io::stdin().lock(), &mut to_server)?;
+
+    // Typing Ctrl-D closes our side only, so the last answers still arrive.
+    stream.shutdown(Shutdown::Write)?;
+    let _ = printer.join();
+
+    Ok(())
+}
@@ -0,0 +1,491 @@
+use std::env;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::blitz::{self, Limits};
+use crate::i18n::Lang;
+use crate::server::DEFAULT_PORT;
+use crate::stats::Format;
+use crate::strategy::StrategyKind;
+use crate::word::Collation;
+
+pub const USAGE: &str = "\
+Usage: guessing_game [OPTIONS]
+       guessing_game leaderboard [--top <N>]
+       guessing_game --leaderboard [--top <N>]
+       guessing_game stats [--format <table|csv|json>]
+       guessing_game serve [--host <IP>] [--port <N>] [OPTIONS]
+       guessing_game join [<HOST>[:<PORT>]]
+       guessing_game reverse [--min <N>] [--max <N>] [--strategy <NAME>]
+       guessing_game replay <LOG>
+       guessing_game words [--words <FILE>] [--case-sensitive] [--normalize <FORM>]
+
+Options:
+  --difficulty <easy|normal|hard>  Start from a preset range and attempt limit
+  --min <N>                        Smallest possible secret number
+  --max <N>                        Largest possible secret number
+  --max-attempts <N>               Number of guesses before the game is lost
+  --seed <N>                       Seed the secret number for a reproducible game
+  --domain <u32|i64|u128|decimal>  The kind of number to guess [default: u32]
+  --precision <N>                  Digits after the point for `decimal` (0 to 3) [default: 2]
+  --tolerance <X>                  How far off a guess may be and still win [default: 0]
+  --reveal, --debug                Show the secret and trace every comparison
+  --hints <N>                      Paid hints allowed; each costs an attempt [default: 0]
+  --hint-penalty <N>               Points added to the leaderboard score per hint [default: 1]
+  --blitz                          Play against the clock [default: 60s in total, 10s a guess]
+  --time-limit <SECS>              Seconds for the whole game (implies --blitz)
+  --guess-time <SECS>              Seconds for each guess; a late one wastes an attempt
+                                   (implies --blitz)
+  --lang <en|vi>                   Language of the game's messages [default: from $LANG]
+  --tui                            Play full screen (needs the `tui` feature)
+  --log <FILE>                     Where to write the session log
+                                   [default: <data dir>/sessions/<start>-<seed>.jsonl]
+  --name <NAME>                    Name recorded on the leaderboard [default: $USER]
+  --top <N>                        Scores shown per difficulty by `leaderboard` [default: 10]
+  --format <table|csv|json>        How `stats` prints its report [default: table]
+  --strategy <binary|random|human> Let a bot play the game
+  --simulate <N>                   Let the bot play N games and report how it did
+  --host <IP>                      Address `serve` listens on [default: 0.0.0.0]
+  --port <N>                       Port `serve` listens on [default: 7878]
+  --words <FILE>                   Word list for `words`, one per line [default: built in]
+  --case-sensitive                 Tell upper and lower case apart in `words`
+  --normalize <FORM>               How `words` compares accented letters:
+                                   none, nfc, nfkc or strip-accents [default: strip-accents]
+  -h, --help                       Print this help
+
+Environment:
+  LANG                             Picks the language when --lang is not given
+  GUESSING_GAME_SEED               Seed used when --seed is not given
+  GUESSING_GAME_DATA_DIR           Where the leaderboard, history and session logs are kept
+                                   [default: $XDG_DATA_HOME/guessing_game]";
+
+/// Named presets for the range and the number of attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn min(self) -> u32 {
+        1
+    }
+
+    pub fn max(self) -> u32 {
+        match self {
+            Difficulty::Easy => 50,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 1000,
+        }
+    }
+
+    // A perfect binary search needs 6 attempts for 1..=50, 7 for 1..=100
+    // and 10 for 1..=1000. Easy leaves plenty of room for mistakes, normal
+    // a few, and hard none at all.
+    pub fn max_attempts(self) -> u32 {
+        match self {
+            Difficulty::Easy => 12,
+            Difficulty::Normal => 10,
+            Difficulty::Hard => 10,
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(format!("unknown difficulty '{s}' (expected easy, normal or hard)")),
+        }
+    }
+}
+
+/// The kind of number the secret is.
+///
+/// Only `u32` games have hints, logs, bots and a leaderboard; the others are
+/// there to show the same `Game` working with other types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Domain {
+    U32,
+    /// Ranges may go below zero.
+    I64,
+    /// Ranges can be astronomically large.
+    U128,
+    /// Fixed-point numbers with `digits` digits after the point.
+    Decimal { digits: u32 },
+}
+
+/// The most digits `--precision` accepts.
+pub const MAX_PRECISION: u32 = 3;
+
+impl fmt::Display for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Domain::U32 => write!(f, "u32"),
+            Domain::I64 => write!(f, "i64"),
+            Domain::U128 => write!(f, "u128"),
+            Domain::Decimal { .. } => write!(f, "decimal"),
+        }
+    }
+}
+
+impl FromStr for Domain {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u32" => Ok(Domain::U32),
+            "i64" => Ok(Domain::I64),
+            "u128" => Ok(Domain::U128),
+            "decimal" => Ok(Domain::Decimal { digits: 2 }),
+            _ => Err(format!(
+                "unknown domain '{s}' (expected u32, i64, u128 or decimal)"
+            )),
+        }
+    }
+}
+
+/// What the program was asked to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    Play,
+    Leaderboard { top: usize },
+    /// Statistics over every game in the history.
+    Stats { format: Format },
+    Simulate { games: u32 },
+    Serve { host: String, port: u16 },
+    Join { addr: String },
+    /// The player thinks of a number and the computer guesses it.
+    Reverse,
+    /// Re-runs a recorded session and checks it still plays out the same.
+    Replay { log: PathBuf },
+    /// Guess a word from a list instead of a number.
+    Words {
+        list: Option<PathBuf>,
+        collation: Collation,
+    },
+}
+
+pub struct Config {
+    pub mode: Mode,
+    /// The preset the game matches, or `None` for a custom game.
+    pub difficulty: Option<Difficulty>,
+    pub min: u32,
+    pub max: u32,
+    pub max_attempts: u32,
+    pub domain: Domain,
+    /// `--min` and `--max` as typed. Games on other domains than `u32` parse
+    /// them for themselves; `min` and `max` are only meaningful for `u32`.
+    pub bounds: (String, String),
+    pub tolerance: Option<String>,
+    pub seed: Option<u64>,
+    pub reveal: bool,
+    pub name: String,
+    /// Set when a bot plays instead of the person at the keyboard.
+    pub strategy: Option<StrategyKind>,
+    pub lang: Lang,
+    /// Set for a blitz game, played against the clock.
+    pub limits: Option<Limits>,
+    pub tui: bool,
+    pub log: Option<PathBuf>,
+    pub hints: u32,
+    pub hint_penalty: u32,
+}
+
+impl Config {
+    /// Builds the configuration from the command line arguments, skipping
+    /// the program name. Explicit `--min`, `--max` and `--max-attempts`
+    /// override whatever the chosen difficulty preset says.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut args = args.peekable();
+        let mut mode = Mode::Play;
+        if let Some(command) = args.next_if(|arg| !arg.starts_with('-')) {
+            mode = match command.as_str() {
+                "leaderboard" => Mode::Leaderboard { top: 10 },
+                "stats" => Mode::Stats {
+                    format: Format::Table,
+                },
+                "serve" => Mode::Serve {
+                    host: String::from("0.0.0.0"),
+                    port: DEFAULT_PORT,
+                },
+                "join" => Mode::Join {
+                    addr: format!("127.0.0.1:{DEFAULT_PORT}"),
+                },
+                "reverse" => Mode::Reverse,
+                "replay" => Mode::Replay { log: PathBuf::new() },
+                "words" => Mode::Words {
+                    list: None,
+                    collation: Collation::default(),
+                },
+                _ => return Err(format!("unknown command '{command}'\n\n{USAGE}")),
+            };
+        }
+
+        let mut difficulty = Difficulty::Normal;
+        let mut min = None;
+        let mut max = None;
+        let mut max_attempts = None;
+        let mut domain = Domain::U32;
+        let mut precision = None;
+        let mut top = None;
+        let mut tolerance = None;
+        let mut seed = None;
+        let mut reveal = false;
+        let mut name = None;
+        let mut strategy = None;
+        let mut lang = None;
+        let mut blitz = false;
+        let mut time_limit = None;
+        let mut guess_time = None;
+        let mut tui = false;
+        let mut log = None;
+        let mut hints = 0;
+        let mut hint_penalty = 1;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--difficulty" => difficulty = value(&mut args, &arg)?.parse()?,
+                "--min" => min = Some(value(&mut args, &arg)?),
+                "--max" => max = Some(value(&mut args, &arg)?),
+                "--max-attempts" => max_attempts = Some(number(&mut args, &arg)?),
+                "--domain" => domain = value(&mut args, &arg)?.parse()?,
+                "--precision" => precision = Some(number(&mut args, &arg)?),
+                "--tolerance" => tolerance = Some(value(&mut args, &arg)?),
+                "--seed" => seed = Some(number(&mut args, &arg)?),
+                "--reveal" | "--debug" => reveal = true,
+                "--name" => name = Some(value(&mut args, &arg)?),
+                "--top" => top = Some(number(&mut args, &arg)?),
+                "--blitz" => blitz = true,
+                "--time-limit" => time_limit = Some(seconds(&mut args, &arg)?),
+                "--guess-time" => guess_time = Some(seconds(&mut args, &arg)?),
+                "--lang" => lang = Some(value(&mut args, &arg)?.parse()?),
+                "--format" => match &mut mode {
+                    Mode::Stats { format } => *format = value(&mut args, &arg)?.parse()?,
+                    _ => return Err(format!("{arg} only applies to `stats`")),
+                },
+                "--tui" if cfg!(feature = "tui") => tui = true,
+                "--tui" => {
+                    return Err(String::from(
+                        "this build has no terminal UI; rebuild with `--features tui`",
+                    ));
+                }
+                "--hints" => hints = number(&mut args, &arg)?,
+                "--hint-penalty" => hint_penalty = number(&mut args, &arg)?,
+                "--log" => log = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--strategy" => strategy = Some(value(&mut args, &arg)?.parse()?),
+                "--simulate" => mode = Mode::Simulate { games: number(&mut args, &arg)? },
+                "--host" => match &mut mode {
+                    Mode::Serve { host, .. } => *host = value(&mut args, &arg)?,
+                    _ => return Err(format!("{arg} only applies to `serve`")),
+                },
+                "--port" => match &mut mode {
+                    Mode::Serve { port, .. } => *port = number(&mut args, &arg)?,
+                    _ => return Err(format!("{arg} only applies to `serve`")),
+                },
+                "--words" => match &mut mode {
+                    Mode::Words { list, .. } => *list = Some(PathBuf::from(value(&mut args, &arg)?)),
+                    _ => return Err(format!("{arg} only applies to `words`")),
+                },
+                "--case-sensitive" => match &mut mode {
+                    Mode::Words { collation, .. } => collation.fold_case = false,
+                    _ => return Err(format!("{arg} only applies to `words`")),
+                },
+                "--normalize" => match &mut mode {
+                    Mode::Words { collation, .. } => {
+                        collation.normalization = value(&mut args, &arg)?.parse()?
+                    }
+                    _ => return Err(format!("{arg} only applies to `words`")),
+                },
+                // `join` and `replay` take one positional argument.
+                _ if !arg.starts_with('-') => match &mut mode {
+                    Mode::Join { addr } => {
+                        *addr = if arg.contains(':') {
+                            arg
+                        } else {
+                            format!("{arg}:{DEFAULT_PORT}")
+                        };
+                    }
+                    Mode::Replay { log: path } => *path = PathBuf::from(arg),
+                    _ => return Err(format!("unexpected argument '{arg}'\n\n{USAGE}")),
+                },
+                "--leaderboard" => mode = Mode::Leaderboard { top: 10 },
+                _ => return Err(format!("unexpected argument '{arg}'\n\n{USAGE}")),
+            }
+        }
+
+        if matches!(&mode, Mode::Replay { log: path } if path.as_os_str().is_empty()) {
+            return Err(String::from("`replay` needs the session log to replay"));
+        }
+
+        // The command line wins over the environment, so a seed exported
+        // for a whole workshop can still be overridden for a single run.
+        let seed = match seed {
+            Some(seed) => Some(seed),
+            None => match env::var("GUESSING_GAME_SEED") {
+                Ok(value) => Some(value.parse().map_err(|_| {
+                    format!("GUESSING_GAME_SEED expects a non-negative number, got '{value}'")
+                })?),
+                Err(_) => None,
+            },
+        };
+
+        let name = name
+            .or_else(|| env::var("USER").ok())
+            .unwrap_or_else(|| String::from("anonymous"));
+
+        // `--top` may come before `--leaderboard`, so it is only checked
+        // once the mode is settled.
+        if let Some(n) = top {
+            match &mut mode {
+                Mode::Leaderboard { top } => *top = n,
+                _ => return Err(String::from("--top only applies to `leaderboard`")),
+            }
+        }
+
+        if let Some(precision) = precision {
+            match &mut domain {
+                Domain::Decimal { digits } if precision <= MAX_PRECISION => *digits = precision,
+                Domain::Decimal { .. } => {
+                    return Err(format!(
+                        "--precision must be at most {MAX_PRECISION}, got {precision}"
+                    ));
+                }
+                _ => return Err(String::from("--precision only applies to --domain decimal")),
+            }
+        }
+
+        // Only the classic game knows about logs, bots, hints and servers,
+        // so anything else has to be a plain game at the keyboard.
+        if (domain != Domain::U32 || tolerance.is_some())
+            && (mode != Mode::Play || strategy.is_some() || tui || hints > 0)
+        {
+            return Err(format!(
+                "--domain {domain} and --tolerance only work for a plain game, \
+                 without a command, --strategy, --tui or --hints"
+            ));
+        }
+
+        // `--blitz` alone uses both default limits; naming either limit
+        // plays with just that one.
+        let limits = match (blitz, time_limit, guess_time) {
+            (false, None, None) => None,
+            (true, None, None) => Some(Limits {
+                total: Some(blitz::DEFAULT_TOTAL),
+                per_guess: Some(blitz::DEFAULT_PER_GUESS),
+            }),
+            (_, total, per_guess) => Some(Limits { total, per_guess }),
+        };
+        if limits.is_some() && (mode != Mode::Play || strategy.is_some() || tui) {
+            return Err(String::from(
+                "blitz games are played at the keyboard, without a command, --strategy or --tui",
+            ));
+        }
+        if limits.is_some() && domain != Domain::U32 {
+            return Err(format!("--domain {domain} has no blitz mode"));
+        }
+
+        let bounds = (
+            min.unwrap_or_else(|| difficulty.min().to_string()),
+            max.unwrap_or_else(|| difficulty.max().to_string()),
+        );
+        let (min, max) = match domain {
+            Domain::U32 => (parse("--min", &bounds.0)?, parse("--max", &bounds.1)?),
+            _ => (difficulty.min(), difficulty.max()),
+        };
+        let max_attempts = max_attempts.unwrap_or(difficulty.max_attempts());
+
+        // Overriding the preset only counts as a custom game if it actually
+        // changes something, so `--difficulty hard --max 1000` is still hard.
+        let matches_preset = min == difficulty.min()
+            && max == difficulty.max()
+            && max_attempts == difficulty.max_attempts();
+
+        let config = Config {
+            mode,
+            difficulty: if matches_preset { Some(difficulty) } else { None },
+            min,
+            max,
+            max_attempts,
+            domain,
+            bounds,
+            tolerance,
+            seed,
+            reveal,
+            name,
+            strategy,
+            lang: lang.unwrap_or_else(Lang::from_env),
+            limits,
+            tui,
+            log,
+            hints,
+            hint_penalty,
+        };
+
+        if config.min > config.max {
+            return Err(format!(
+                "--min ({}) must not be greater than --max ({})",
+                config.min, config.max
+            ));
+        }
+        if config.max_attempts == 0 {
+            return Err("--max-attempts must be at least 1".to_string());
+        }
+
+        Ok(config)
+    }
+
+    /// How the leaderboard groups this game: the preset name or "custom",
+    /// followed by "blitz" for games against the clock.
+    pub fn difficulty_label(&self) -> String {
+        let label = match self.difficulty {
+            Some(difficulty) => difficulty.to_string(),
+            None => String::from("custom"),
+        };
+        match self.limits {
+            Some(_) => format!("{label} blitz"),
+            None => label,
+        }
+    }
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or(format!("{flag} needs a value"))
+}
+
+fn number<T: FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<T, String> {
+    parse(flag, &value(args, flag)?)
+}
+
+fn seconds(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<Duration, String> {
+    match number(args, flag)? {
+        0 => Err(format!("{flag} must be at least 1 second")),
+        seconds => Ok(Duration::from_secs(seconds)),
+    }
+}
+
+fn parse<T: FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{flag} expects a non-negative number, got '{value}'"))
+}
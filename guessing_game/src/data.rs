@@ -0,0 +1,21 @@
+use std::env;
+use std::path::PathBuf;
+
+/// The directory where everything that outlives a session is kept.
+///
+/// `GUESSING_GAME_DATA_DIR` wins, then the XDG base directory spec is
+/// followed: `$XDG_DATA_HOME/guessing_game`, falling back to
+/// `~/.local/share/guessing_game`.
+pub fn data_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("GUESSING_GAME_DATA_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+
+    let base = match env::var_os("XDG_DATA_HOME") {
+        // The spec says relative paths are invalid and should be ignored.
+        Some(dir) if PathBuf::from(&dir).is_absolute() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".local/share"),
+    };
+
+    Some(base.join("guessing_game"))
+}
@@ -0,0 +1,318 @@
+use std::cmp::Ordering;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::hint::{Hint, HintError, HintKind, NEAR_DISTANCE};
+use crate::number::Number;
+
+/// What the game answers to a single guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuessOutcome {
+    TooSmall,
+    TooBig,
+    Correct,
+    /// The guess can't be the secret because it lies outside the game's
+    /// range. It doesn't cost an attempt.
+    OutOfRange,
+    /// The game was already won or lost before this guess.
+    GameOver,
+}
+
+impl GuessOutcome {
+    /// How the guess compared to the secret, if it was compared at all.
+    pub fn ordering(self) -> Option<Ordering> {
+        match self {
+            GuessOutcome::TooSmall => Some(Ordering::Less),
+            GuessOutcome::TooBig => Some(Ordering::Greater),
+            GuessOutcome::Correct => Some(Ordering::Equal),
+            GuessOutcome::OutOfRange | GuessOutcome::GameOver => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    InProgress,
+    Won,
+    Lost,
+}
+
+/// The rules of the guessing game, without any input or output.
+///
+/// A `Game` only changes through `guess` and `hint`, which makes it easy to
+/// drive from stdin, from a test, or from any other front end.
+///
+/// All the rules need is a secret that guesses can be compared with, so
+/// the secret can be anything `Ord`: a number, or a `Word` as in
+/// `Game<Word>`. Number games add a range and a tolerance on top, and the
+/// secret is a `u32` unless another `Number` type is asked for, as in
+/// `Game<i64>`.
+#[derive(Debug, Clone)]
+pub struct Game<T: Ord + Clone = u32> {
+    secret: T,
+    /// The smallest and largest guesses allowed, if there are any bounds.
+    range: Option<(T, T)>,
+    /// The guesses that count as correct: just the secret, unless a
+    /// tolerance makes "close enough" as good as equal.
+    correct: (T, T),
+    max_attempts: u32,
+    attempts: u32,
+    status: Status,
+    // The closest guesses so far on either side of the secret.
+    after: Option<T>,
+    before: Option<T>,
+    first_guess: Option<T>,
+    last_guess: Option<T>,
+    hint_budget: u32,
+    hints_used: u32,
+}
+
+impl<T: Ord + Clone> Game<T> {
+    /// Starts a game that takes any guess, however far from the secret.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_attempts` is zero.
+    pub fn unbounded(secret: T, max_attempts: u32) -> Game<T> {
+        assert!(max_attempts > 0, "max_attempts must be at least 1");
+
+        Game {
+            correct: (secret.clone(), secret.clone()),
+            secret,
+            range: None,
+            max_attempts,
+            attempts: 0,
+            status: Status::InProgress,
+            after: None,
+            before: None,
+            first_guess: None,
+            last_guess: None,
+            hint_budget: 0,
+            hints_used: 0,
+        }
+    }
+
+    /// Starts a game with a secret picked by `rng` from `choices`, or `None`
+    /// if there is nothing to pick from.
+    pub fn pick(choices: &[T], max_attempts: u32, rng: &mut impl Rng) -> Option<Game<T>> {
+        let secret = choices.choose(rng)?;
+        Some(Game::unbounded(secret.clone(), max_attempts))
+    }
+
+    pub fn guess(&mut self, guess: T) -> GuessOutcome {
+        if self.status != Status::InProgress {
+            return GuessOutcome::GameOver;
+        }
+        if let Some((min, max)) = &self.range
+            && (guess < *min || guess > *max)
+        {
+            return GuessOutcome::OutOfRange;
+        }
+
+        self.attempts += 1;
+        self.first_guess.get_or_insert_with(|| guess.clone());
+        self.last_guess = Some(guess.clone());
+
+        let (lowest, highest) = &self.correct;
+        let outcome = if guess < *lowest {
+            if self.after.as_ref().is_none_or(|after| guess > *after) {
+                self.after = Some(guess);
+            }
+            GuessOutcome::TooSmall
+        } else if guess > *highest {
+            if self.before.as_ref().is_none_or(|before| guess < *before) {
+                self.before = Some(guess);
+            }
+            GuessOutcome::TooBig
+        } else {
+            self.status = Status::Won;
+            GuessOutcome::Correct
+        };
+
+        if self.status == Status::InProgress && self.attempts == self.max_attempts {
+            self.status = Status::Lost;
+        }
+
+        outcome
+    }
+
+    /// Uses up an attempt without a guess, as when a guess took too long.
+    pub fn waste_attempt(&mut self) {
+        if self.status != Status::InProgress {
+            return;
+        }
+
+        self.attempts += 1;
+        if self.attempts == self.max_attempts {
+            self.status = Status::Lost;
+        }
+    }
+
+    /// Ends the game as lost, as when the time for the whole game is up.
+    pub fn forfeit(&mut self) {
+        if self.status == Status::InProgress {
+            self.status = Status::Lost;
+        }
+    }
+
+    pub fn secret(&self) -> &T {
+        &self.secret
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn attempts_left(&self) -> u32 {
+        self.max_attempts - self.attempts
+    }
+
+    /// The first guess that counted, if any.
+    pub fn first_guess(&self) -> Option<&T> {
+        self.first_guess.as_ref()
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.status != Status::InProgress
+    }
+
+    /// The closest guesses so far below and above the secret, if any.
+    pub fn closest(&self) -> (Option<&T>, Option<&T>) {
+        (self.after.as_ref(), self.before.as_ref())
+    }
+}
+
+impl<T: Number> Game<T> {
+    /// Starts a game with a secret picked by `rng` from `min..=max`.
+    pub fn new(min: T, max: T, max_attempts: u32, rng: &mut impl Rng) -> Game<T> {
+        let secret = T::random_in(min, max, rng);
+        Game::with_secret(min, max, secret, max_attempts)
+    }
+
+    /// Starts a game with a known secret.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, if the secret is outside `min..=max` or if
+    /// `max_attempts` is zero.
+    pub fn with_secret(min: T, max: T, secret: T, max_attempts: u32) -> Game<T> {
+        assert!(min <= max, "min ({min}) must not be greater than max ({max})");
+        assert!(
+            (min..=max).contains(&secret),
+            "secret ({secret}) must be between {min} and {max}"
+        );
+
+        Game {
+            range: Some((min, max)),
+            ..Game::unbounded(secret, max_attempts)
+        }
+    }
+
+    /// Counts guesses at most `tolerance` away from the secret as correct.
+    /// Games need an exact guess by default.
+    pub fn with_tolerance(mut self, tolerance: T) -> Game<T> {
+        self.correct = self.secret.around(tolerance);
+        self
+    }
+
+    pub fn min(&self) -> T {
+        self.bounds().0
+    }
+
+    pub fn max(&self) -> T {
+        self.bounds().1
+    }
+
+    fn bounds(&self) -> (T, T) {
+        self.range.expect("number games are made with a range")
+    }
+
+    /// The smallest and largest numbers the secret can still be.
+    pub fn remaining(&self) -> (T, T) {
+        if self.status == Status::Won {
+            return (self.secret, self.secret);
+        }
+
+        let (min, max) = self.bounds();
+        let (after, before) = self.closest();
+        (
+            after.map_or(min, |after| after.next_up()),
+            before.map_or(max, |before| before.next_down()),
+        )
+    }
+}
+
+// Hints are about whole numbers (parity, divisors), so they are only
+// offered for the classic game.
+impl Game<u32> {
+    /// Allows up to `budget` paid hints. Games have none by default.
+    pub fn with_hints(mut self, budget: u32) -> Game<u32> {
+        self.hint_budget = budget;
+        self
+    }
+
+    /// Answers a question about the secret. Every hint but `Range` uses up
+    /// one hint from the budget and one attempt.
+    pub fn hint(&mut self, kind: HintKind) -> Result<Hint, HintError> {
+        if self.status != Status::InProgress {
+            return Err(HintError::GameOver);
+        }
+
+        if kind.is_free() {
+            let (low, high) = self.remaining();
+            return Ok(Hint::Range { low, high });
+        }
+
+        if self.hint_budget == 0 {
+            return Err(HintError::Disabled);
+        }
+        if self.hints_used == self.hint_budget {
+            return Err(HintError::NoHintsLeft);
+        }
+        if self.attempts_left() <= 1 {
+            return Err(HintError::NoAttemptToSpare);
+        }
+
+        let hint = match kind {
+            HintKind::Range => unreachable!("range hints are free"),
+            HintKind::Parity if self.secret.is_multiple_of(2) => Hint::Even,
+            HintKind::Parity => Hint::Odd,
+            HintKind::DivisibleBy(divisor) if divisor < 2 => return Err(HintError::BadDivisor),
+            HintKind::DivisibleBy(divisor) => Hint::DivisibleBy {
+                divisor,
+                divisible: self.secret.is_multiple_of(divisor),
+            },
+            HintKind::Near => {
+                let guess = self.last_guess.ok_or(HintError::NoGuessYet)?;
+                Hint::Near {
+                    guess,
+                    within: guess.abs_diff(self.secret) <= NEAR_DISTANCE,
+                }
+            }
+        };
+
+        self.hints_used += 1;
+        self.attempts += 1;
+
+        Ok(hint)
+    }
+
+    pub fn hints_used(&self) -> u32 {
+        self.hints_used
+    }
+
+    pub fn hints_left(&self) -> u32 {
+        self.hint_budget - self.hints_used
+    }
+}
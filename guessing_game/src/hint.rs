@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// How close "near" is for `hint near`.
+pub const NEAR_DISTANCE: u32 = 10;
+
+/// The divisor used when `hint div` doesn't name one.
+pub const DEFAULT_DIVISOR: u32 = 3;
+
+/// What the player can ask about the secret.
+///
+/// `Range` only repeats what the answers so far already tell, so it is free.
+/// The others reveal something new and cost an attempt and a hint from the
+/// budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HintKind {
+    Range,
+    Parity,
+    DivisibleBy(u32),
+    Near,
+}
+
+impl HintKind {
+    pub fn is_free(self) -> bool {
+        self == HintKind::Range
+    }
+}
+
+/// What a hint says about the secret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    Range { low: u32, high: u32 },
+    Even,
+    Odd,
+    DivisibleBy { divisor: u32, divisible: bool },
+    Near { guess: u32, within: bool },
+}
+
+/// Why a hint was refused. A refused hint costs nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HintError {
+    Disabled,
+    NoHintsLeft,
+    /// A hint costs an attempt, and spending the last one would lose the game.
+    NoAttemptToSpare,
+    /// `near` compares with the last guess, so there has to be one.
+    NoGuessYet,
+    BadDivisor,
+    GameOver,
+}
+
+impl std::error::Error for HintError {}
@@ -0,0 +1,104 @@
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::data;
+use crate::game::{Game, Status};
+
+/// How a finished game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Won,
+    Lost,
+    /// The player quit, or the input ran out, before the game was decided.
+    Abandoned,
+}
+
+/// One finished game, won or not.
+///
+/// Unlike the leaderboard, which only keeps wins, the history keeps every
+/// game so that `stats` can tell how often games are lost.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub name: String,
+    pub difficulty: String,
+    pub min: u32,
+    pub max: u32,
+    pub max_attempts: u32,
+    pub outcome: Outcome,
+    pub attempts: u32,
+    pub first_guess: Option<u32>,
+    pub hints_used: u32,
+    pub elapsed_ms: u64,
+    /// Seconds since the Unix epoch.
+    pub played_at: u64,
+}
+
+impl Record {
+    pub fn new(name: &str, game: &Game, elapsed: Duration, difficulty: &str) -> Record {
+        Record {
+            name: name.to_string(),
+            difficulty: difficulty.to_string(),
+            min: game.min(),
+            max: game.max(),
+            max_attempts: game.max_attempts(),
+            outcome: match game.status() {
+                Status::Won => Outcome::Won,
+                Status::Lost => Outcome::Lost,
+                Status::InProgress => Outcome::Abandoned,
+            },
+            attempts: game.attempts(),
+            first_guess: game.first_guess().copied(),
+            hints_used: game.hints_used(),
+            elapsed_ms: elapsed.as_millis() as u64,
+            played_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+        }
+    }
+}
+
+/// Where the history is kept: `<data dir>/history.jsonl`.
+pub fn default_path() -> Option<PathBuf> {
+    Some(data::data_dir()?.join("history.jsonl"))
+}
+
+/// Adds a game to the end of the history, one JSON object per line, so
+/// recording a game never has to read or rewrite the earlier ones.
+pub fn append(path: &Path, record: &Record) -> Result<(), Box<dyn Error>> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut line = serde_json::to_string(record)?;
+    line.push('\n');
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// Reads every recorded game, treating a missing file as no games.
+pub fn load(path: &Path) -> Result<Vec<Record>, Box<dyn Error>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).map_err(|err| {
+                format!("line {} of {} is not a valid game: {err}", index + 1, path.display())
+                    .into()
+            })
+        })
+        .collect()
+}
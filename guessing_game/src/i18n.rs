@@ -0,0 +1,278 @@
+//! The player-facing text of the game, in more than one language.
+//!
+//! Messages live in Fluent catalogs under `locales/`, one file per
+//! language, and are built into the binary. Fluent handles the grammar
+//! that `format!` can't, such as "1 attempt" against "2 attempts".
+
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+use crate::hint::{Hint, HintError, NEAR_DISTANCE};
+use crate::input::InputError;
+use crate::number::NumberError;
+use crate::reverse::Contradiction;
+
+/// The languages the game speaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    Vi,
+}
+
+impl Lang {
+    /// The language named by `$LANG` (as in `vi_VN.UTF-8`), or English if
+    /// it is unset or not one the game knows.
+    pub fn from_env() -> Lang {
+        env::var("LANG")
+            .ok()
+            .and_then(|lang| lang.parse().ok())
+            .unwrap_or(Lang::En)
+    }
+
+    fn catalog(self) -> &'static str {
+        match self {
+            Lang::En => include_str!("../locales/en.ftl"),
+            Lang::Vi => include_str!("../locales/vi.ftl"),
+        }
+    }
+}
+
+impl fmt::Display for Lang {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Lang::En => "en",
+            Lang::Vi => "vi",
+        };
+        write!(f, "{code}")
+    }
+}
+
+impl FromStr for Lang {
+    type Err = String;
+
+    /// Accepts a bare code (`vi`) as well as a locale (`vi-VN`, `vi_VN.UTF-8`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.split(['_', '-', '.']).next().unwrap_or(s);
+        match code.to_lowercase().as_str() {
+            "en" => Ok(Lang::En),
+            "vi" => Ok(Lang::Vi),
+            _ => Err(format!("unknown language '{s}' (expected en or vi)")),
+        }
+    }
+}
+
+/// A message catalog for one language, with English behind it for any
+/// message the language doesn't have.
+///
+/// The bundles are the thread-safe kind, so the server's connection
+/// threads can share one catalog.
+pub struct Messages {
+    bundles: Vec<FluentBundle<FluentResource>>,
+}
+
+impl Messages {
+    pub fn new(lang: Lang) -> Messages {
+        let mut bundles = vec![bundle(lang)];
+        if lang != Lang::En {
+            bundles.push(bundle(Lang::En));
+        }
+        Messages { bundles }
+    }
+
+    /// A message without arguments.
+    pub fn get(&self, id: &str) -> String {
+        self.format(id, &[])
+    }
+
+    /// A message with its `{ $name }` placeables filled in. Pass counts as
+    /// numbers, so that Fluent can pick the right plural.
+    pub fn format(&self, id: &str, args: &[(&str, FluentValue)]) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+
+        for bundle in &self.bundles {
+            let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+                continue;
+            };
+            // A missing argument is shown as `{$name}` in the text, which is
+            // easier to spot and fix than a panic in the middle of a game.
+            let mut errors = Vec::new();
+            return bundle
+                .format_pattern(pattern, Some(&fluent_args), &mut errors)
+                .into_owned();
+        }
+
+        // Every message is in the English catalog, so this is a typo in
+        // the code. Showing the id keeps the game going.
+        id.to_string()
+    }
+
+    /// The help text for the number game.
+    pub fn commands(&self) -> String {
+        let near = self.format("help-near", &[("distance", NEAR_DISTANCE.into())]);
+        [
+            self.get("help-intro"),
+            format!("  hint          {}", self.get("help-hint")),
+            format!("  hint parity   {}", self.get("help-parity")),
+            format!("  hint div <N>  {}", self.get("help-div")),
+            format!("  hint near     {near}"),
+            format!("  help          {}", self.get("help-help")),
+            format!("  quit          {}", self.get("help-quit")),
+            String::new(),
+            self.get("help-paid-note"),
+        ]
+        .join("\n")
+    }
+
+    /// The help text for the word game.
+    pub fn word_commands(&self) -> String {
+        [
+            self.get("help-word-intro"),
+            format!("  hint   {}", self.get("help-word-hint")),
+            format!("  help   {}", self.get("help-help")),
+            format!("  quit   {}", self.get("help-word-quit")),
+        ]
+        .join("\n")
+    }
+}
+
+fn bundle(lang: Lang) -> FluentBundle<FluentResource> {
+    let id: LanguageIdentifier = lang.to_string().parse().expect("language codes are valid");
+    let resource = FluentResource::try_new(lang.catalog().to_string())
+        .unwrap_or_else(|(_, errors)| panic!("the {lang} catalog doesn't parse: {errors:?}"));
+
+    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+    // Fluent wraps every argument in invisible Unicode isolation marks to
+    // protect right-to-left text. Terminals print them as garbage, and
+    // neither language needs them.
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .unwrap_or_else(|errors| panic!("the {lang} catalog has duplicate messages: {errors:?}"));
+    bundle
+}
+
+/// Types that are shown to the player in the player's language.
+pub trait Localize {
+    fn localize(&self, messages: &Messages) -> String;
+}
+
+/// `Display` for errors that are also `Localize`: the English catalog's
+/// text, so each message is only written once.
+macro_rules! display_in_english {
+    ($($t:ty),*) => {
+        $(
+            impl fmt::Display for $t {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(&self.localize(&Messages::new(Lang::En)))
+                }
+            }
+        )*
+    };
+}
+
+display_in_english!(HintError, InputError);
+
+impl Localize for Hint {
+    fn localize(&self, messages: &Messages) -> String {
+        match *self {
+            Hint::Range { low, high } => {
+                messages.format("hint-range", &[("low", low.into()), ("high", high.into())])
+            }
+            Hint::Even => messages.get("hint-even"),
+            Hint::Odd => messages.get("hint-odd"),
+            Hint::DivisibleBy { divisor, divisible } => {
+                let id = if divisible { "hint-divisible" } else { "hint-not-divisible" };
+                messages.format(id, &[("divisor", divisor.into())])
+            }
+            Hint::Near { guess, within } => {
+                let id = if within { "hint-near" } else { "hint-far" };
+                messages.format(id, &[("guess", guess.into()), ("distance", NEAR_DISTANCE.into())])
+            }
+        }
+    }
+}
+
+impl Localize for HintError {
+    fn localize(&self, messages: &Messages) -> String {
+        messages.get(match self {
+            HintError::Disabled => "hint-disabled",
+            HintError::NoHintsLeft => "hint-none-left",
+            HintError::NoAttemptToSpare => "hint-no-attempt-to-spare",
+            HintError::NoGuessYet => "hint-no-guess-yet",
+            HintError::BadDivisor => "hint-bad-divisor",
+            HintError::GameOver => "game-over",
+        })
+    }
+}
+
+impl Localize for InputError {
+    fn localize(&self, messages: &Messages) -> String {
+        match self {
+            InputError::Empty => messages.get("input-empty"),
+            InputError::NotANumber(input) => {
+                messages.format("input-not-a-number", &[("input", input.as_str().into())])
+            }
+            InputError::OutOfU32Range(input) => messages.format(
+                "input-out-of-range",
+                &[
+                    ("input", input.as_str().into()),
+                    ("min", u32::MIN.into()),
+                    ("max", u32::MAX.into()),
+                ],
+            ),
+            InputError::UnknownHint(input) => {
+                messages.format("input-unknown-hint", &[("input", input.as_str().into())])
+            }
+            InputError::Unparsable { input, reason } => messages.format(
+                "input-unparsable",
+                &[("input", input.as_str().into()), ("reason", reason.localize(messages).into())],
+            ),
+            InputError::NotOneWord(input) => {
+                messages.format("input-not-one-word", &[("input", input.as_str().into())])
+            }
+        }
+    }
+}
+
+impl Localize for NumberError {
+    fn localize(&self, messages: &Messages) -> String {
+        match *self {
+            NumberError::Invalid => messages.get("reason-invalid"),
+            NumberError::TooPrecise { digits } => {
+                messages.format("reason-too-precise", &[("digits", digits.into())])
+            }
+            NumberError::TooLarge => messages.get("reason-too-large"),
+            NumberError::TooSmall => messages.get("reason-too-small"),
+        }
+    }
+}
+
+impl Localize for Contradiction {
+    fn localize(&self, messages: &Messages) -> String {
+        match *self {
+            Contradiction::Between {
+                higher_than,
+                lower_than,
+            } => messages.format(
+                "contradiction-between",
+                &[("higher", higher_than.into()), ("lower", lower_than.into())],
+            ),
+            Contradiction::AboveMax { higher_than, max } => messages.format(
+                "contradiction-above-max",
+                &[("higher", higher_than.into()), ("max", max.into())],
+            ),
+            Contradiction::BelowMin { lower_than, min } => messages.format(
+                "contradiction-below-min",
+                &[("lower", lower_than.into()), ("min", min.into())],
+            ),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::num::IntErrorKind;
+
+use serde::{Deserialize, Serialize};
+
+use crate::hint::{DEFAULT_DIVISOR, HintKind};
+use crate::number::{Number, NumberError};
+
+/// One line typed by the player, once it has been understood.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command<T = u32> {
+    Guess(T),
+    Hint(HintKind),
+    Help,
+    Quit,
+}
+
+/// Why a line couldn't be understood as a guess or a command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputError {
+    Empty,
+    NotANumber(String),
+    /// A number, but one that a `u32` can't hold: negative or too large.
+    OutOfU32Range(String),
+    UnknownHint(String),
+    /// Not a valid guess for a game played with another number type.
+    Unparsable { input: String, reason: NumberError },
+    /// The word game takes one word at a time.
+    NotOneWord(String),
+}
+
+impl std::error::Error for InputError {}
+
+/// Parses a line read from the player. Surrounding whitespace is ignored and
+/// commands are case-insensitive.
+pub fn parse(line: &str) -> Result<Command, InputError> {
+    let input = line.trim();
+    if let Some(command) = parse_command(input) {
+        return command;
+    }
+
+    match input.parse::<u32>() {
+        Ok(num) => Ok(Command::Guess(num)),
+        Err(err) => match err.kind() {
+            IntErrorKind::Empty => Err(InputError::Empty),
+            IntErrorKind::PosOverflow => Err(InputError::OutOfU32Range(input.to_string())),
+            // `u32` has no sign, so "-5" is reported as an invalid digit.
+            // Tell it apart from text by looking at the characters.
+            _ if is_integer(input) => Err(InputError::OutOfU32Range(input.to_string())),
+            _ => Err(InputError::NotANumber(input.to_string())),
+        },
+    }
+}
+
+/// Like `parse`, but for a game played with any `Number` type. The
+/// commands are the same; only guesses are read differently.
+pub fn parse_as<T: Number>(line: &str) -> Result<Command<T>, InputError> {
+    let input = line.trim();
+    if let Some(command) = parse_command(input) {
+        return command;
+    }
+    if input.is_empty() {
+        return Err(InputError::Empty);
+    }
+
+    input.parse().map(Command::Guess).map_err(|err: T::Err| InputError::Unparsable {
+        input: input.to_string(),
+        reason: err.into(),
+    })
+}
+
+/// Like `parse`, for the word game: a guess is any single word.
+pub fn parse_word(line: &str) -> Result<Command<String>, InputError> {
+    let input = line.trim();
+    if let Some(command) = parse_command(input) {
+        return command;
+    }
+
+    match input.split_whitespace().count() {
+        0 => Err(InputError::Empty),
+        1 => Ok(Command::Guess(input.to_string())),
+        _ => Err(InputError::NotOneWord(input.to_string())),
+    }
+}
+
+/// Recognizes everything but a guess, or returns `None`.
+fn parse_command<T>(input: &str) -> Option<Result<Command<T>, InputError>> {
+    let lowercase = input.to_lowercase();
+    let mut words = lowercase.split_whitespace();
+    if words.next() == Some("hint") {
+        return Some(
+            parse_hint(words.collect())
+                .map(Command::Hint)
+                .ok_or_else(|| InputError::UnknownHint(input.to_string())),
+        );
+    }
+
+    match lowercase.as_str() {
+        "help" | "?" => Some(Ok(Command::Help)),
+        "quit" | "exit" | "q" => Some(Ok(Command::Quit)),
+        _ => None,
+    }
+}
+
+fn parse_hint(words: Vec<&str>) -> Option<HintKind> {
+    match words.as_slice() {
+        [] | ["range"] => Some(HintKind::Range),
+        ["parity" | "even" | "odd"] => Some(HintKind::Parity),
+        ["div" | "divisible"] => Some(HintKind::DivisibleBy(DEFAULT_DIVISOR)),
+        ["div" | "divisible", divisor] => divisor.parse().ok().map(HintKind::DivisibleBy),
+        ["near"] => Some(HintKind::Near),
+        _ => None,
+    }
+}
+
+fn is_integer(input: &str) -> bool {
+    let digits = input.strip_prefix(['-', '+']).unwrap_or(input);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
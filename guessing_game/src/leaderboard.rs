@@ -0,0 +1,165 @@
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::data;
+use crate::game::Game;
+
+/// Order in which difficulties are listed; custom games come last. Each is
+/// followed by its blitz version.
+pub const DIFFICULTIES: [&str; 4] = ["easy", "normal", "hard", "custom"];
+
+/// One won game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Score {
+    pub name: String,
+    pub attempts: u32,
+    /// Extra points for the hints that were bought and, in blitz games, for
+    /// the time taken. Older scores have none.
+    #[serde(default)]
+    pub penalty: u32,
+    pub elapsed_ms: u64,
+    pub min: u32,
+    pub max: u32,
+    pub max_attempts: u32,
+    pub difficulty: String,
+    /// Seconds since the Unix epoch.
+    pub played_at: u64,
+}
+
+impl Score {
+    /// Records a finished game as a score, charging `hint_penalty` points
+    /// for each hint used.
+    pub fn new(
+        name: &str,
+        game: &Game,
+        elapsed: Duration,
+        difficulty: &str,
+        hint_penalty: u32,
+    ) -> Score {
+        Score {
+            name: name.to_string(),
+            attempts: game.attempts(),
+            penalty: game.hints_used() * hint_penalty,
+            elapsed_ms: elapsed.as_millis() as u64,
+            min: game.min(),
+            max: game.max(),
+            max_attempts: game.max_attempts(),
+            difficulty: difficulty.to_string(),
+            played_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+        }
+    }
+
+    /// Adds a point for every `per_point` the game took, so that in blitz
+    /// games speed counts as much as attempts.
+    pub fn with_time_penalty(mut self, per_point: Duration) -> Score {
+        self.penalty += (self.elapsed_ms / per_point.as_millis() as u64) as u32;
+        self
+    }
+
+    /// What the score is ranked by: lower is better.
+    pub fn points(&self) -> u32 {
+        self.attempts + self.penalty
+    }
+}
+
+/// All recorded scores, stored as JSON in the data directory.
+#[derive(Debug, Default)]
+pub struct Leaderboard {
+    scores: Vec<Score>,
+}
+
+impl Leaderboard {
+    pub fn default_path() -> Option<PathBuf> {
+        Some(data::data_dir()?.join("leaderboard.json"))
+    }
+
+    /// Reads the leaderboard, treating a missing file as an empty one.
+    pub fn load(path: &Path) -> Result<Leaderboard, Box<dyn Error>> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Leaderboard::default()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let scores = serde_json::from_str(&contents)
+            .map_err(|err| format!("{} is not a valid leaderboard: {err}", path.display()))?;
+
+        Ok(Leaderboard { scores })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(&self.scores)?)?;
+        Ok(())
+    }
+
+    pub fn add(&mut self, score: Score) {
+        self.scores.push(score);
+    }
+
+    /// The best `n` scores of a difficulty: fewest points first, then the
+    /// fastest, then the earliest.
+    pub fn top(&self, difficulty: &str, n: usize) -> Vec<&Score> {
+        let mut scores: Vec<&Score> = self
+            .scores
+            .iter()
+            .filter(|score| score.difficulty == difficulty)
+            .collect();
+
+        scores.sort_by_key(|score| (score.points(), score.elapsed_ms, score.played_at));
+        scores.truncate(n);
+        scores
+    }
+
+    /// Renders the top `n` scores of every difficulty that has any.
+    pub fn render(&self, n: usize) -> String {
+        let mut out = String::new();
+
+        let groups = DIFFICULTIES
+            .iter()
+            .flat_map(|difficulty| [difficulty.to_string(), format!("{difficulty} blitz")]);
+
+        for difficulty in groups {
+            let scores = self.top(&difficulty, n);
+            if scores.is_empty() {
+                continue;
+            }
+
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("== {difficulty} ==\n"));
+
+            for (rank, score) in scores.iter().enumerate() {
+                let penalty = match score.penalty {
+                    0 => String::new(),
+                    penalty => format!(" +{penalty}"),
+                };
+                out.push_str(&format!(
+                    "{:>3}. {:<16} {:>3} attempts{penalty:<4} {:>8.1}s  ({}-{})\n",
+                    rank + 1,
+                    score.name,
+                    score.attempts,
+                    score.elapsed_ms as f64 / 1000.0,
+                    score.min,
+                    score.max,
+                ));
+            }
+        }
+
+        if out.is_empty() {
+            out.push_str("No scores yet. Win a game to get on the board!\n");
+        }
+
+        out
+    }
+}
@@ -0,0 +1,33 @@
+//! The rules of the guessing game from chapter 2, split from the I/O in
+//! `main.rs` so they can be reused and tested on their own.
+
+pub mod blitz;
+pub mod client;
+pub mod config;
+pub mod data;
+pub mod game;
+pub mod hint;
+pub mod history;
+pub mod i18n;
+pub mod input;
+pub mod leaderboard;
+pub mod number;
+pub mod reverse;
+pub mod server;
+pub mod session;
+pub mod simulate;
+pub mod stats;
+pub mod strategy;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod word;
+
+pub use config::{Config, Difficulty, Domain, Mode};
+pub use game::{Game, GuessOutcome, Status};
+pub use hint::{Hint, HintError, HintKind};
+pub use input::{Command, InputError};
+pub use leaderboard::{Leaderboard, Score};
+pub use number::{Decimal, Number};
+pub use server::Server;
+pub use session::{Event, Session};
+pub use strategy::{Strategy, StrategyKind};
@@ -1,48 +1,836 @@
-use std::cmp::Ordering;
-use std::io;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+use std::process;
+use std::time::Duration;
 
-use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use guessing_game::blitz::{self, Clock, Input};
+use guessing_game::config::{MAX_PRECISION, USAGE};
+use guessing_game::hint::{HintError, HintKind};
+use guessing_game::history::{self, Record};
+use guessing_game::i18n::{Localize, Messages};
+use guessing_game::input::{self, Command};
+use guessing_game::session::{self, Header, Recorder};
+use guessing_game::client;
+use guessing_game::reverse::{Answer, Reverse};
+use guessing_game::simulate::simulate;
+use guessing_game::stats::{Format, Report};
+use guessing_game::strategy::{self, binary_search_bound};
+use guessing_game::word::{self, Collation, Word};
+use guessing_game::{
+    Config, Decimal, Domain, Event, Game, GuessOutcome, Leaderboard, Mode, Number, Score, Server,
+    Session, Status, StrategyKind,
+};
 
 fn main() {
-    println!("Guess the number!");
+    if env::args().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{USAGE}");
+        return;
+    }
 
-    let secret_number = rand::thread_rng().gen_range(1..=100);
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
 
-    println!("The secret number is: {secret_number}");
+    match (&config.mode, config.strategy) {
+        (Mode::Play, None) if config.domain != Domain::U32 || config.tolerance.is_some() => {
+            match config.domain {
+                Domain::U32 => play_number::<u32>(&config),
+                Domain::I64 => play_number::<i64>(&config),
+                Domain::U128 => play_number::<u128>(&config),
+                // The precision is part of the type, so each one the command
+                // line accepts needs its own arm.
+                Domain::Decimal { digits: 0 } => play_number::<Decimal<0>>(&config),
+                Domain::Decimal { digits: 1 } => play_number::<Decimal<1>>(&config),
+                Domain::Decimal { digits: 2 } => play_number::<Decimal<2>>(&config),
+                Domain::Decimal { digits: 3 } => play_number::<Decimal<3>>(&config),
+                Domain::Decimal { digits } => {
+                    unreachable!("--precision {digits} is above {MAX_PRECISION}")
+                }
+            }
+        }
+        (Mode::Play, None) => play(&config),
+        (Mode::Play, Some(kind)) => watch(&config, kind),
+        (Mode::Leaderboard { top }, _) => show_leaderboard(*top),
+        (Mode::Stats { format }, _) => show_stats(*format),
+        (Mode::Simulate { games }, kind) => {
+            run_simulation(&config, kind.unwrap_or(StrategyKind::BinarySearch), *games)
+        }
+        (Mode::Reverse, kind) => reverse(&config, kind.unwrap_or(StrategyKind::BinarySearch)),
+        (Mode::Replay { log }, _) => replay(&config, log),
+        (Mode::Words { list, collation }, _) => play_words(&config, list.as_deref(), *collation),
+        (Mode::Serve { host, port }, _) => serve(&config, host, *port),
+        (Mode::Join { addr }, _) => {
+            if let Err(err) = client::join(addr.as_str()) {
+                eprintln!("Could not play on {addr}: {err}");
+                process::exit(1);
+            }
+        }
+    }
+}
 
-    loop {
-        println!("Please input your guess!");
+// The same seed always produces the same secret, which makes a session
+// reproducible. Without one, the generator is seeded from the OS.
+fn rng(config: &Config) -> StdRng {
+    match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
 
-        // The :: syntax in the ::new line indicates that new is 
-        // an associated function of the String type. 
-        // An associated function is a function that’s implemented on a type,
-        // in this case String. This new function creates a new, empty string. 
-        // You’ll find a new function on many types because it’s a common name
-        // for a function that makes a new value of some kind.
-        let mut guess = String::new();
+fn play(config: &Config) {
+    // Every session is seeded, even without --seed, so its log can always
+    // be replayed.
+    let seed = config.seed.unwrap_or_else(rand::random);
+    let header = Header::new(
+        seed,
+        config.min,
+        config.max,
+        config.max_attempts,
+        config.hints,
+    );
+    let mut session = Session::start(&header, recorder(config, &header));
+    let messages = Messages::new(config.lang);
+    let game = session.game();
 
+    if config.reveal {
+        eprintln!("[debug] The secret number is: {}", game.secret());
+    }
 
-        // OR std::io::stdin() if std::io is not imported
-        // io::stdin() returns an instance of std::io::Stdin
-        io::stdin()
-            .read_line(&mut guess)
-            .expect("Failed to read line");
+    #[cfg(feature = "tui")]
+    if config.tui {
+        play_tui(config, &mut session, &messages);
+        return;
+    }
+
+    println!("{}", messages.get("guess-the-number"));
+    println!(
+        "{}",
+        messages.format(
+            "intro",
+            &[
+                ("min", config.min.into()),
+                ("max", config.max.into()),
+                ("attempts", config.max_attempts.into()),
+            ],
+        )
+    );
+
+    let clock = config.limits.map(Clock::start);
+    if let Some(clock) = &clock {
+        let limits = clock.limits();
+        if let Some(total) = limits.total {
+            println!("{}", messages.format("blitz-total", &[("seconds", total.as_secs().into())]));
+        }
+        if let Some(per_guess) = limits.per_guess {
+            println!(
+                "{}",
+                messages.format("blitz-per-guess", &[("seconds", per_guess.as_secs().into())])
+            );
+        }
+    }
 
-        let guess: u32 = match guess.trim().parse() {
-            Ok(num) => num, 
-            Err(_) => continue,
+    while !session.is_over() {
+        let event = match &clock {
+            Some(clock) => next_blitz_event(clock, &mut session, &messages),
+            None => next_event(&mut session, &messages),
         };
 
-        println!("You guessed: {guess}");
+        let game = session.game();
+        match event {
+            Event::Guess { guess, outcome } => {
+                println!("{}", messages.format("you-guessed", &[("guess", guess.into())]));
+
+                if config.reveal {
+                    eprintln!("[debug] {guess} is {outcome:?}");
+                }
+
+                match outcome {
+                    GuessOutcome::TooSmall => println!("{}", messages.get("too-small")),
+                    GuessOutcome::TooBig => println!("{}", messages.get("too-big")),
+                    GuessOutcome::Correct => println!("{}", messages.get("you-win")),
+                    GuessOutcome::OutOfRange => println!(
+                        "{}",
+                        messages.format(
+                            "out-of-range",
+                            &[("min", game.min().into()), ("max", game.max().into())],
+                        )
+                    ),
+                    GuessOutcome::GameOver => {}
+                }
+
+                if config.reveal {
+                    let (low, high) = game.remaining();
+                    eprintln!("[debug] The secret is between {low} and {high}");
+                }
+            }
+            Event::Hint { kind, result } => match result {
+                Ok(hint) => {
+                    println!("{}", hint.localize(&messages));
+                    if !kind.is_free() {
+                        println!(
+                            "{}",
+                            messages.format(
+                                "hint-cost",
+                                &[
+                                    ("attempts", game.attempts_left().into()),
+                                    ("hints", game.hints_left().into()),
+                                ],
+                            )
+                        );
+                    }
+                }
+                Err(err) => println!("{}", err.localize(&messages)),
+            },
+            Event::Help => println!("{}", messages.commands()),
+            Event::Quit => println!("{}", secret_message(&messages, "gave-up", game.secret())),
+            Event::Invalid { error } => println!("{}", error.localize(&messages)),
+            Event::EndOfInput => {
+                println!();
+                println!("{}", secret_message(&messages, "no-more-input", game.secret()));
+            }
+            // Printed on a line of their own, since the prompt is still
+            // waiting for the guess that never came.
+            Event::TimedOut => {
+                println!();
+                println!(
+                    "{}",
+                    messages.format("timed-out", &[("attempts", game.attempts_left().into())])
+                );
+            }
+            Event::OutOfTime => {
+                println!();
+                println!("{}", messages.get("time-up"));
+            }
+        }
+    }
+
+    let game = session.game();
+    record_history(config, game, session.started().elapsed());
+    match game.status() {
+        Status::Won => {
+            let mut score = Score::new(
+                &config.name,
+                game,
+                session.started().elapsed(),
+                &config.difficulty_label(),
+                config.hint_penalty,
+            );
+            if config.limits.is_some() {
+                score = score.with_time_penalty(blitz::TIME_PER_POINT);
+                println!(
+                    "{}",
+                    messages.format(
+                        "blitz-score",
+                        &[
+                            ("points", score.points().into()),
+                            ("attempts", score.attempts.into()),
+                            ("penalty", score.penalty.into()),
+                        ],
+                    )
+                );
+            }
+            record_score(score);
+        }
+        Status::Lost => println!("{}", secret_message(&messages, "you-lose", game.secret())),
+        Status::InProgress => {}
+    }
+}
+
+/// Reads the next line and lets the session handle it.
+fn next_event(session: &mut Session, messages: &Messages) -> Event {
+    println!("{}", messages.get("input-guess"));
+
+    // The :: syntax in the ::new line indicates that new is
+    // an associated function of the String type.
+    // An associated function is a function that’s implemented on a type,
+    // in this case String. This new function creates a new, empty string.
+    // You’ll find a new function on many types because it’s a common name
+    // for a function that makes a new value of some kind.
+    let mut guess = String::new();
+
+
+    // OR std::io::stdin() if std::io is not imported
+    // io::stdin() returns an instance of std::io::Stdin
+    let bytes = io::stdin()
+        .read_line(&mut guess)
+        .expect("Failed to read line");
+
+    // read_line returns Ok(0) once stdin is closed (Ctrl-D, or the end
+    // of a piped file). Every further call would return 0 again.
+    if bytes == 0 {
+        session.end_of_input()
+    } else {
+        session.handle_line(&guess)
+    }
+}
+
+/// Like `next_event`, but gives up waiting when the blitz clock says so.
+/// The blocking `read_line` happens on the clock's own thread.
+fn next_blitz_event(clock: &Clock, session: &mut Session, messages: &Messages) -> Event {
+    match (clock.time_left(), clock.limits().per_guess) {
+        (Some(left), _) => {
+            // Round up, so the count only reaches 0 when the time is up.
+            let seconds = (left.as_millis() as u64).div_ceil(1000);
+            println!(
+                "{} {}",
+                messages.get("input-guess"),
+                messages.format("blitz-time-left", &[("seconds", seconds.into())])
+            );
+        }
+        // Each guess gets the whole of its time, starting now.
+        (None, Some(per_guess)) => println!(
+            "{} {}",
+            messages.get("input-guess"),
+            messages.format("blitz-guess-time-left", &[("seconds", per_guess.as_secs().into())])
+        ),
+        (None, None) => println!("{}", messages.get("input-guess")),
+    }
+
+    match clock.next_line() {
+        Input::Line(line) => session.handle_line(&line),
+        Input::TimedOut => session.time_out(),
+        Input::OutOfTime => session.out_of_time(),
+        Input::Closed => session.end_of_input(),
+    }
+}
+
+/// One of the messages that end a game by revealing the secret.
+fn secret_message(messages: &Messages, id: &str, secret: impl ToString) -> String {
+    messages.format(id, &[("secret", secret.to_string().into())])
+}
+
+/// The same game as `play`, for any kind of number. It is kept simple: no
+/// paid hints, no session log and no leaderboard.
+fn play_number<T: Number>(config: &Config) {
+    let (min, max, tolerance) = number_bounds::<T>(config).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let mut game = Game::new(min, max, config.max_attempts, &mut rng(config)).with_tolerance(tolerance);
+    let messages = Messages::new(config.lang);
+    // Not every number type converts to a Fluent number without losing
+    // digits (u128 doesn't), so they are passed as text.
+    let range = [("min", min.to_string().into()), ("max", max.to_string().into())];
+
+    if config.reveal {
+        eprintln!("[debug] The secret number is: {}", game.secret());
+    }
+
+    println!("{}", messages.get("guess-the-number"));
+    println!(
+        "{}",
+        messages.format(
+            "intro",
+            &[range[0].clone(), range[1].clone(), ("attempts", config.max_attempts.into())],
+        )
+    );
+    if tolerance != T::default() {
+        println!(
+            "{}",
+            messages.format("tolerance-note", &[("tolerance", tolerance.to_string().into())])
+        );
+    }
+
+    while !game.is_over() {
+        println!("{}", messages.get("input-guess"));
+
+        let mut line = String::new();
+        let bytes = io::stdin()
+            .read_line(&mut line)
+            .expect("Failed to read line");
+        if bytes == 0 {
+            println!();
+            println!("{}", secret_message(&messages, "no-more-input", game.secret()));
+            return;
+        }
+
+        match input::parse_as::<T>(&line) {
+            Ok(Command::Guess(guess)) => {
+                println!(
+                    "{}",
+                    messages.format("you-guessed", &[("guess", guess.to_string().into())])
+                );
 
+                let outcome = game.guess(guess);
+                if config.reveal {
+                    eprintln!("[debug] {guess} is {outcome:?}");
+                }
 
-        match guess.cmp(&secret_number) {
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
-            Ordering::Equal => {
-                println!("You win!");
-                break;
+                match outcome {
+                    GuessOutcome::TooSmall => println!("{}", messages.get("too-small")),
+                    GuessOutcome::TooBig => println!("{}", messages.get("too-big")),
+                    GuessOutcome::Correct => {
+                        println!("{}", secret_message(&messages, "you-win-close", game.secret()))
+                    }
+                    GuessOutcome::OutOfRange => {
+                        println!("{}", messages.format("out-of-range", &range))
+                    }
+                    GuessOutcome::GameOver => {}
+                }
+
+                if config.reveal {
+                    let (low, high) = game.remaining();
+                    eprintln!("[debug] The secret is between {low} and {high}");
+                }
+            }
+            Ok(Command::Hint(HintKind::Range)) => {
+                let (low, high) = game.remaining();
+                println!(
+                    "{}",
+                    messages.format(
+                        "hint-range",
+                        &[("low", low.to_string().into()), ("high", high.to_string().into())],
+                    )
+                );
+            }
+            Ok(Command::Hint(_)) => println!("{}", HintError::Disabled.localize(&messages)),
+            Ok(Command::Help) => println!("{}", messages.commands()),
+            Ok(Command::Quit) => {
+                println!("{}", secret_message(&messages, "gave-up", game.secret()));
+                return;
+            }
+            Err(err) => println!("{}", err.localize(&messages)),
+        }
+    }
+
+    if game.status() == Status::Lost {
+        println!("{}", secret_message(&messages, "you-lose", game.secret()));
+    }
+}
+
+/// The guessing game with a secret word, answered alphabetically.
+fn play_words(config: &Config, list: Option<&Path>, collation: Collation) {
+    let words = match list {
+        Some(path) => word::load_list(path).unwrap_or_else(|err| {
+            eprintln!("Could not read the word list {}: {err}", path.display());
+            process::exit(1);
+        }),
+        None => word::parse_list(word::BUNDLED),
+    };
+    let words: Vec<Word> = words.iter().map(|text| collation.word(text)).collect();
+    let Some(mut game) = Game::pick(&words, config.max_attempts, &mut rng(config)) else {
+        eprintln!("The word list is empty.");
+        process::exit(1);
+    };
+
+    let messages = Messages::new(config.lang);
+    let word = |id: &str, word: &str| messages.format(id, &[("word", word.into())]);
+
+    if config.reveal {
+        eprintln!(
+            "[debug] The secret word is: {} (compared as '{}')",
+            game.secret(),
+            game.secret().key()
+        );
+    }
+
+    println!("{}", messages.get("guess-the-word"));
+    println!(
+        "{}",
+        messages.format(
+            "words-intro",
+            &[("count", words.len().into()), ("attempts", config.max_attempts.into())],
+        )
+    );
+
+    while !game.is_over() {
+        println!("{}", messages.get("input-guess"));
+
+        let mut line = String::new();
+        let bytes = io::stdin()
+            .read_line(&mut line)
+            .expect("Failed to read line");
+        if bytes == 0 {
+            println!();
+            println!("{}", secret_message(&messages, "word-no-more-input", game.secret()));
+            return;
+        }
+
+        match input::parse_word(&line) {
+            Ok(Command::Guess(guess)) => {
+                println!("{}", messages.format("you-guessed", &[("guess", guess.as_str().into())]));
+                match game.guess(collation.word(&guess)) {
+                    GuessOutcome::TooSmall => println!("{}", word("word-after", &guess)),
+                    GuessOutcome::TooBig => println!("{}", word("word-before", &guess)),
+                    GuessOutcome::Correct => {
+                        println!("{}", secret_message(&messages, "word-win", game.secret()))
+                    }
+                    GuessOutcome::OutOfRange | GuessOutcome::GameOver => {}
+                }
+            }
+            Ok(Command::Hint(HintKind::Range)) => match game.closest() {
+                (None, None) => println!("{}", messages.get("make-guess-first")),
+                (Some(after), None) => println!("{}", word("word-after", after.text())),
+                (None, Some(before)) => println!("{}", word("word-before", before.text())),
+                (Some(after), Some(before)) => println!(
+                    "{}",
+                    messages.format(
+                        "word-between",
+                        &[("after", after.text().into()), ("before", before.text().into())],
+                    )
+                ),
             },
+            Ok(Command::Hint(_)) => println!("{}", HintError::Disabled.localize(&messages)),
+            Ok(Command::Help) => println!("{}", messages.word_commands()),
+            Ok(Command::Quit) => {
+                println!("{}", secret_message(&messages, "word-gave-up", game.secret()));
+                return;
+            }
+            Err(err) => println!("{}", err.localize(&messages)),
+        }
+    }
+
+    if game.status() == Status::Lost {
+        println!("{}", secret_message(&messages, "word-lose", game.secret()));
+    }
+}
+
+fn number_bounds<T: Number>(config: &Config) -> Result<(T, T, T), String> {
+    let parse = |flag: &str, text: &str| {
+        text.parse::<T>()
+            .map_err(|err| format!("{flag} expects a {} number, got '{text}': {err}", config.domain))
+    };
+
+    let min = parse("--min", &config.bounds.0)?;
+    let max = parse("--max", &config.bounds.1)?;
+    let tolerance = match &config.tolerance {
+        Some(text) => parse("--tolerance", text)?,
+        None => T::default(),
+    };
+
+    if min > max {
+        return Err(format!("--min ({min}) must not be greater than --max ({max})"));
+    }
+    if tolerance < T::default() {
+        return Err(format!("--tolerance must not be negative, got {tolerance}"));
+    }
+    Ok((min, max, tolerance))
+}
+
+fn recorder(config: &Config, header: &Header) -> Option<Recorder> {
+    let path = config.log.clone().or_else(|| header.default_log_path())?;
+
+    match Recorder::create(&path, header) {
+        Ok(recorder) => Some(recorder),
+        Err(err) => {
+            eprintln!("Could not create the session log {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+#[cfg(feature = "tui")]
+fn play_tui(config: &Config, session: &mut Session, messages: &Messages) {
+    let elapsed = guessing_game::tui::run(session, messages).unwrap_or_else(|err| {
+        eprintln!("The terminal UI failed: {err}");
+        process::exit(1);
+    });
+    let game = session.game();
+    record_history(config, game, elapsed);
+
+    match game.status() {
+        Status::Won => {
+            println!(
+                "{}",
+                messages.format("you-won-in", &[("attempts", game.attempts().into())])
+            );
+            record_score(Score::new(
+                &config.name,
+                game,
+                elapsed,
+                &config.difficulty_label(),
+                config.hint_penalty,
+            ));
+        }
+        Status::Lost => println!("{}", secret_message(messages, "you-lose", game.secret())),
+        Status::InProgress => println!("{}", secret_message(messages, "gave-up", game.secret())),
+    }
+}
+
+/// Lets a bot play a single game, printing each of its guesses.
+fn watch(config: &Config, kind: StrategyKind) {
+    let mut rng = rng(config);
+    let mut game = Game::new(config.min, config.max, config.max_attempts, &mut rng);
+    let mut bot = kind.build(config.min, config.max, &mut rng);
+    let messages = Messages::new(config.lang);
+
+    println!(
+        "{}",
+        messages.format(
+            "watch-intro",
+            &[
+                ("bot", kind.to_string().into()),
+                ("min", config.min.into()),
+                ("max", config.max.into()),
+            ],
+        )
+    );
+
+    strategy::autoplay(&mut game, bot.as_mut(), |guess, outcome| {
+        let answer = messages.get(match outcome {
+            GuessOutcome::TooSmall => "too-small",
+            GuessOutcome::TooBig => "too-big",
+            GuessOutcome::Correct => "watch-correct",
+            GuessOutcome::OutOfRange => "watch-out-of-range",
+            GuessOutcome::GameOver => "watch-game-over",
+        });
+        // Padded here, so the answers line up whatever the language.
+        let guess = format!("{guess:<10}");
+        println!(
+            "{}",
+            messages.format("watch-guess", &[("guess", guess.into()), ("answer", answer.into())])
+        );
+    });
+
+    match game.status() {
+        Status::Won => println!(
+            "{}",
+            messages.format("watch-won", &[("attempts", game.attempts().into())])
+        ),
+        _ => println!("{}", secret_message(&messages, "watch-lost", game.secret())),
+    }
+}
+
+fn run_simulation(config: &Config, kind: StrategyKind, games: u32) {
+    let mut rng = rng(config);
+    let summary = simulate(kind, config, games, &mut rng);
+
+    let messages = Messages::new(config.lang);
+    // The labels are padded to the longest, so the numbers line up in any
+    // language.
+    let labels = ["simulate-won", "simulate-average", "simulate-max"].map(|id| messages.get(id));
+    let width = labels.iter().map(|label| label.chars().count()).max().unwrap_or(0);
+    let [won, average, max] = labels;
+    let row = |label: String, value: String| println!("  {label:<width$} {value}");
+
+    println!(
+        "{}",
+        messages.format(
+            "simulate-intro",
+            &[
+                ("strategy", kind.to_string().into()),
+                ("games", games.into()),
+                ("min", config.min.into()),
+                ("max", config.max.into()),
+                ("attempts", config.max_attempts.into()),
+            ],
+        )
+    );
+    row(won, format!("{} / {}", summary.wins, summary.games));
+    row(average, format!("{:.2}", summary.average_attempts()));
+    row(max, summary.max_attempts.to_string());
+    println!(
+        "  {}",
+        messages.format(
+            "simulate-bound",
+            &[("attempts", binary_search_bound(config.min, config.max).into())],
+        )
+    );
+}
+
+/// The player thinks of a number and answers the computer's guesses.
+fn reverse(config: &Config, kind: StrategyKind) {
+    let mut rng = rng(config);
+    let strategy = kind.build(config.min, config.max, &mut rng);
+    let mut computer = Reverse::new(config.min, config.max, strategy);
+    let messages = Messages::new(config.lang);
+
+    println!(
+        "{}",
+        messages.format(
+            "reverse-intro",
+            &[("min", config.min.into()), ("max", config.max.into())],
+        )
+    );
+
+    loop {
+        let guess = computer.next_guess();
+
+        let answer = loop {
+            println!("{}", messages.format("reverse-ask", &[("guess", guess.into())]));
+
+            let mut line = String::new();
+            let bytes = io::stdin()
+                .read_line(&mut line)
+                .expect("Failed to read line");
+            if bytes == 0 {
+                println!();
+                println!("{}", messages.get("reverse-no-more-answers"));
+                return;
+            }
+
+            match line.parse::<Answer>() {
+                Ok(answer) => break answer,
+                Err(_) => println!(
+                    "{}",
+                    messages.format("reverse-not-an-answer", &[("input", line.trim().into())])
+                ),
+            }
+        };
+
+        match computer.answer(guess, answer) {
+            Ok(true) => {
+                println!(
+                    "{}",
+                    messages.format("reverse-got-it", &[("guesses", computer.guesses().into())])
+                );
+                return;
+            }
+            Ok(false) => {}
+            Err(contradiction) => {
+                println!(
+                    "{}",
+                    messages.format(
+                        "reverse-cheating",
+                        &[("reason", contradiction.localize(&messages).into())],
+                    )
+                );
+                return;
+            }
+        }
+    }
+}
+
+fn replay(config: &Config, path: &Path) {
+    let replay = File::open(path)
+        .map_err(|err| err.into())
+        .and_then(|file| session::replay(BufReader::new(file)))
+        .unwrap_or_else(|err| {
+            eprintln!("Could not replay {}: {err}", path.display());
+            process::exit(1);
+        });
+
+    let messages = Messages::new(config.lang);
+    let header = &replay.header;
+    println!(
+        "{}",
+        messages.format(
+            "replay-header",
+            &[
+                // A seed is an id, not an amount: no digit grouping.
+                ("seed", header.seed.to_string().into()),
+                ("min", header.min.into()),
+                ("max", header.max.into()),
+                ("attempts", header.max_attempts.into()),
+                ("entries", replay.entries.into()),
+            ],
+        )
+    );
+
+    if replay.mismatches.is_empty() {
+        println!("{}", messages.get("replay-all-match"));
+        return;
+    }
+
+    for mismatch in &replay.mismatches {
+        let input = match &mismatch.input {
+            Some(input) => format!("'{input}'"),
+            None => messages.get("replay-end-of-input"),
+        };
+        // The events are shown as they are logged, which is the same in
+        // every language.
+        println!(
+            "{}",
+            messages.format(
+                "replay-mismatch",
+                &[
+                    ("index", mismatch.index.into()),
+                    ("input", input.into()),
+                    ("recorded", format!("{:?}", mismatch.recorded).into()),
+                    ("replayed", format!("{:?}", mismatch.replayed).into()),
+                ],
+            )
+        );
+    }
+    println!(
+        "{}",
+        messages.format(
+            "replay-differ",
+            &[("differ", replay.mismatches.len().into()), ("entries", replay.entries.into())],
+        )
+    );
+    process::exit(1);
+}
+
+fn serve(config: &Config, host: &str, port: u16) {
+    let server = Server::bind((host, port), config, rng(config)).unwrap_or_else(|err| {
+        eprintln!("Could not listen on {host}:{port}: {err}");
+        process::exit(1);
+    });
+
+    if let Ok(addr) = server.local_addr() {
+        let messages = Messages::new(config.lang);
+        println!("{}", messages.format("serve-listening", &[("addr", addr.to_string().into())]));
+        println!(
+            "{}",
+            messages.format("serve-connect", &[("port", addr.port().to_string().into())])
+        );
+    }
+
+    server.run();
+}
+
+fn record_score(score: Score) {
+    let Some(path) = Leaderboard::default_path() else {
+        eprintln!("Can't find a data directory; the score was not saved.");
+        return;
+    };
+
+    // A broken leaderboard shouldn't spoil the win, so only warn about it.
+    let result = Leaderboard::load(&path).and_then(|mut leaderboard| {
+        leaderboard.add(score);
+        leaderboard.save(&path)
+    });
+    if let Err(err) = result {
+        eprintln!("Could not save the score to {}: {err}", path.display());
+    }
+}
+
+/// Adds the game to the history `stats` reads, whatever its outcome.
+fn record_history(config: &Config, game: &Game, elapsed: Duration) {
+    let Some(path) = history::default_path() else {
+        eprintln!("Can't find a data directory; the game was not added to the history.");
+        return;
+    };
+
+    let record = Record::new(&config.name, game, elapsed, &config.difficulty_label());
+    if let Err(err) = history::append(&path, &record) {
+        eprintln!("Could not add the game to {}: {err}", path.display());
+    }
+}
+
+fn show_stats(format: Format) {
+    let Some(path) = history::default_path() else {
+        eprintln!("Can't find a data directory to read the history from.");
+        process::exit(1);
+    };
+
+    match history::load(&path) {
+        Ok(records) => print!("{}", Report::new(&records).render(format)),
+        Err(err) => {
+            eprintln!("Could not read the history: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+fn show_leaderboard(top: usize) {
+    let Some(path) = Leaderboard::default_path() else {
+        eprintln!("Can't find a data directory to read the leaderboard from.");
+        process::exit(1);
+    };
+
+    match Leaderboard::load(&path) {
+        Ok(leaderboard) => print!("{}", leaderboard.render(top)),
+        Err(err) => {
+            eprintln!("Could not read the leaderboard: {err}");
+            process::exit(1);
         }
     }
 }
@@ -0,0 +1,226 @@
+//! The kinds of numbers the game can be played with.
+//!
+//! `Game` is generic over `Number`, so the same rules work for `u32` (the
+//! chapter 2 game), negative `i64` ranges, huge `u128` ranges, and
+//! fixed-precision `Decimal`s that only have to be guessed "close enough".
+//! Games of anything else that is `Ord`, like words, make do without it.
+
+use std::fmt;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// What the game needs from a number: it has to be comparable, readable
+/// from what the player types, printable, and possible to pick at random.
+/// Why a guess doesn't parse has to be something the player can be told in
+/// their language.
+pub trait Number:
+    Copy + Ord + fmt::Display + fmt::Debug + Default + FromStr<Err: fmt::Display + Into<NumberError>>
+{
+    /// Picks a number in `min..=max`.
+    fn random_in(min: Self, max: Self, rng: &mut impl Rng) -> Self;
+
+    /// The smallest number after `self`, or `self` if there is none.
+    fn next_up(self) -> Self;
+
+    /// The largest number before `self`, or `self` if there is none.
+    fn next_down(self) -> Self;
+
+    /// The smallest and largest numbers at most `distance` away from
+    /// `self`, which must not be negative.
+    fn around(self, distance: Self) -> (Self, Self);
+}
+
+macro_rules! integer_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn random_in(min: Self, max: Self, rng: &mut impl Rng) -> Self {
+                    rng.gen_range(min..=max)
+                }
+
+                fn next_up(self) -> Self {
+                    self.saturating_add(1)
+                }
+
+                fn next_down(self) -> Self {
+                    self.saturating_sub(1)
+                }
+
+                fn around(self, distance: Self) -> (Self, Self) {
+                    (self.saturating_sub(distance), self.saturating_add(distance))
+                }
+            }
+        )*
+    };
+}
+
+integer_number!(u32, u64, u128, i32, i64, i128);
+
+/// Why a guess isn't a number of the game's kind, whatever that kind is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberError {
+    Invalid,
+    TooPrecise { digits: u32 },
+    TooLarge,
+    TooSmall,
+}
+
+impl From<ParseIntError> for NumberError {
+    fn from(err: ParseIntError) -> NumberError {
+        match err.kind() {
+            IntErrorKind::PosOverflow => NumberError::TooLarge,
+            IntErrorKind::NegOverflow => NumberError::TooSmall,
+            _ => NumberError::Invalid,
+        }
+    }
+}
+
+impl From<DecimalError> for NumberError {
+    fn from(err: DecimalError) -> NumberError {
+        match err {
+            DecimalError::Invalid => NumberError::Invalid,
+            DecimalError::TooPrecise { digits } => NumberError::TooPrecise { digits },
+            DecimalError::TooLarge => NumberError::TooLarge,
+        }
+    }
+}
+
+/// A decimal number with exactly `DIGITS` digits after the point, stored as
+/// a whole number of its smallest unit: with two digits, 3.14 is 314.
+///
+/// Working in whole units keeps comparisons exact, which floating point
+/// numbers can't promise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal<const DIGITS: u32> {
+    units: i64,
+}
+
+impl<const DIGITS: u32> Decimal<DIGITS> {
+    const SCALE: i64 = 10_i64.pow(DIGITS);
+
+    pub fn from_units(units: i64) -> Self {
+        Decimal { units }
+    }
+
+    pub fn units(self) -> i64 {
+        self.units
+    }
+}
+
+impl<const DIGITS: u32> fmt::Display for Decimal<DIGITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.units < 0 { "-" } else { "" };
+        let units = self.units.unsigned_abs();
+        let scale = Self::SCALE.unsigned_abs();
+        let whole = units / scale;
+
+        if DIGITS == 0 {
+            return write!(f, "{sign}{whole}");
+        }
+        write!(
+            f,
+            "{sign}{whole}.{:0width$}",
+            units % scale,
+            width = DIGITS as usize
+        )
+    }
+}
+
+/// Why some text isn't a `Decimal`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecimalError {
+    Invalid,
+    TooPrecise { digits: u32 },
+    TooLarge,
+}
+
+impl fmt::Display for DecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecimalError::Invalid => write!(f, "not a decimal number"),
+            DecimalError::TooPrecise { digits } => {
+                write!(f, "more than {digits} digits after the decimal point")
+            }
+            DecimalError::TooLarge => write!(f, "too large"),
+        }
+    }
+}
+
+impl std::error::Error for DecimalError {}
+
+impl<const DIGITS: u32> FromStr for Decimal<DIGITS> {
+    type Err = DecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+            return Err(DecimalError::Invalid);
+        }
+        // Trailing zeros don't add precision: 1.50 is fine with one digit.
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > DIGITS as usize {
+            return Err(DecimalError::TooPrecise { digits: DIGITS });
+        }
+
+        // Work in i128 so the sign goes on before the range check: i64::MIN
+        // has no positive counterpart.
+        let whole: i128 = match whole {
+            "" => 0,
+            whole => whole.parse().map_err(|_| DecimalError::TooLarge)?,
+        };
+        let fraction: i128 = format!("{fraction:0<width$}", width = DIGITS as usize)
+            .parse()
+            .unwrap_or(0);
+
+        let units = whole
+            .checked_mul(i128::from(Self::SCALE))
+            .and_then(|units| units.checked_add(fraction))
+            .ok_or(DecimalError::TooLarge)?;
+        let units = if negative { -units } else { units };
+
+        Ok(Decimal {
+            units: i64::try_from(units).map_err(|_| DecimalError::TooLarge)?,
+        })
+    }
+}
+
+impl<const DIGITS: u32> Number for Decimal<DIGITS> {
+    fn random_in(min: Self, max: Self, rng: &mut impl Rng) -> Self {
+        Decimal {
+            units: rng.gen_range(min.units..=max.units),
+        }
+    }
+
+    fn next_up(self) -> Self {
+        Decimal {
+            units: self.units.saturating_add(1),
+        }
+    }
+
+    fn next_down(self) -> Self {
+        Decimal {
+            units: self.units.saturating_sub(1),
+        }
+    }
+
+    fn around(self, distance: Self) -> (Self, Self) {
+        (
+            Decimal {
+                units: self.units.saturating_sub(distance.units),
+            },
+            Decimal {
+                units: self.units.saturating_add(distance.units),
+            },
+        )
+    }
+}
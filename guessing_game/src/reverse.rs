@@ -0,0 +1,128 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use crate::strategy::Strategy;
+
+/// What the player says about the computer's guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    /// The player's number is higher than the guess.
+    Higher,
+    /// The player's number is lower than the guess.
+    Lower,
+    Correct,
+}
+
+impl Answer {
+    /// How the guess compares to the player's number. This is the game's
+    /// usual `guess.cmp(&secret)`, just told by a person instead.
+    pub fn ordering(self) -> Ordering {
+        match self {
+            Answer::Higher => Ordering::Less,
+            Answer::Lower => Ordering::Greater,
+            Answer::Correct => Ordering::Equal,
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "h" | "higher" | "+" => Ok(Answer::Higher),
+            "l" | "lower" | "-" => Ok(Answer::Lower),
+            "c" | "correct" | "y" | "yes" | "=" => Ok(Answer::Correct),
+            other => Err(format!("'{other}' is not an answer. Type higher, lower or correct.")),
+        }
+    }
+}
+
+/// Answers that can't all be true.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Contradiction {
+    Between { higher_than: u32, lower_than: u32 },
+    AboveMax { higher_than: u32, max: u32 },
+    BelowMin { lower_than: u32, min: u32 },
+}
+
+/// The game turned around: the player holds the secret and the computer
+/// guesses, narrowing the range with the player's answers.
+pub struct Reverse {
+    min: u32,
+    max: u32,
+    strategy: Box<dyn Strategy>,
+    guesses: u32,
+    // The tightest answers so far, to explain a contradiction.
+    higher_than: Option<u32>,
+    lower_than: Option<u32>,
+}
+
+impl Reverse {
+    pub fn new(min: u32, max: u32, strategy: Box<dyn Strategy>) -> Reverse {
+        Reverse {
+            min,
+            max,
+            strategy,
+            guesses: 0,
+            higher_than: None,
+            lower_than: None,
+        }
+    }
+
+    pub fn next_guess(&mut self) -> u32 {
+        self.guesses += 1;
+        self.strategy.next_guess()
+    }
+
+    pub fn guesses(&self) -> u32 {
+        self.guesses
+    }
+
+    /// Takes the player's answer about `guess`. Returns whether the number
+    /// was found, or the contradiction if the answer can't be true.
+    pub fn answer(&mut self, guess: u32, answer: Answer) -> Result<bool, Contradiction> {
+        match answer {
+            Answer::Higher => {
+                let higher_than = self.higher_than.map_or(guess, |h| h.max(guess));
+                if higher_than >= self.max {
+                    return Err(Contradiction::AboveMax {
+                        higher_than,
+                        max: self.max,
+                    });
+                }
+                if let Some(lower_than) = self.lower_than
+                    && higher_than + 1 >= lower_than
+                {
+                    return Err(Contradiction::Between {
+                        higher_than,
+                        lower_than,
+                    });
+                }
+                self.higher_than = Some(higher_than);
+            }
+            Answer::Lower => {
+                let lower_than = self.lower_than.map_or(guess, |l| l.min(guess));
+                if lower_than <= self.min {
+                    return Err(Contradiction::BelowMin {
+                        lower_than,
+                        min: self.min,
+                    });
+                }
+                if let Some(higher_than) = self.higher_than
+                    && higher_than + 1 >= lower_than
+                {
+                    return Err(Contradiction::Between {
+                        higher_than,
+                        lower_than,
+                    });
+                }
+                self.lower_than = Some(lower_than);
+            }
+            Answer::Correct => return Ok(true),
+        }
+
+        self.strategy.observe(guess, answer.ordering());
+        Ok(false)
+    }
+}
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::config::Config;
+use crate::game::{Game, GuessOutcome, Status};
+use crate::hint::HintKind;
+use crate::i18n::{Localize, Messages};
+use crate::input::{self, Command};
+
+pub const DEFAULT_PORT: u16 = 7878;
+
+/// How long a player's client may leave a line unread before the server
+/// gives up on it and hangs up.
+pub const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A game shared by everyone connected over TCP.
+///
+/// Each round has one secret. Every player gets their own attempts at it,
+/// everyone sees everyone's guesses, and the first to find it wins the
+/// round. Any line-based client works, including `nc`.
+///
+/// Nothing is written to a player while the lobby is locked: messages go
+/// into the player's outbox, and a thread per player sends them on. A
+/// client that stops reading only holds up its own thread, until the write
+/// times out and the player is dropped.
+pub struct Server {
+    listener: TcpListener,
+    lobby: Arc<Mutex<Lobby>>,
+}
+
+struct Lobby {
+    messages: Messages,
+    min: u32,
+    max: u32,
+    max_attempts: u32,
+    hints: u32,
+    rng: StdRng,
+    round: u32,
+    secret: u32,
+    next_id: usize,
+    players: HashMap<usize, Player>,
+}
+
+struct Player {
+    name: String,
+    outbox: Sender<String>,
+    game: Game,
+}
+
+impl Server {
+    pub fn bind(addr: impl ToSocketAddrs, config: &Config, mut rng: StdRng) -> io::Result<Server> {
+        let listener = TcpListener::bind(addr)?;
+        let secret = rng.gen_range(config.min..=config.max);
+
+        let lobby = Lobby {
+            messages: Messages::new(config.lang),
+            min: config.min,
+            max: config.max,
+            max_attempts: config.max_attempts,
+            hints: config.hints,
+            rng,
+            round: 1,
+            secret,
+            next_id: 0,
+            players: HashMap::new(),
+        };
+
+        Ok(Server {
+            listener,
+            lobby: Arc::new(Mutex::new(lobby)),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Accepts players forever, one thread per connection.
+    pub fn run(&self) {
+        for stream in self.listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(err) => {
+                    eprintln!("Connection failed: {err}");
+                    continue;
+                }
+            };
+
+            let lobby = Arc::clone(&self.lobby);
+            thread::spawn(move || {
+                if let Err(err) = handle_connection(stream, &lobby) {
+                    eprintln!("Player connection error: {err}");
+                }
+            });
+        }
+    }
+}
+
+fn handle_connection(stream: TcpStream, lobby: &Mutex<Lobby>) -> io::Result<()> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream.try_clone()?;
+
+    // Take the text out first: nothing is written with the lobby locked.
+    let welcome = lobby.lock().unwrap().messages.get("server-welcome");
+    writeln!(writer, "{welcome}")?;
+    let mut name = String::new();
+    if reader.read_line(&mut name)? == 0 {
+        return Ok(());
+    }
+    let name = match name.trim() {
+        "" => lobby.lock().unwrap().messages.get("server-anonymous"),
+        name => name.to_string(),
+    };
+
+    let (outbox, inbox) = mpsc::channel();
+    let sender = thread::spawn(move || send_lines(writer, inbox));
+    let id = lobby.lock().unwrap().join(name, outbox);
+
+    let mut line = String::new();
+    loop {
+        line.clear();
+        // An error is as final as the end of the stream: either way the
+        // player is gone.
+        match reader.read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+
+        let mut lobby = lobby.lock().unwrap();
+        match input::parse(&line) {
+            Ok(Command::Guess(guess)) => lobby.guess(id, guess),
+            Ok(Command::Hint(kind)) => lobby.hint(id, kind),
+            Ok(Command::Help) => lobby.tell(id, &lobby.messages.commands()),
+            Ok(Command::Quit) => break,
+            Err(err) => lobby.tell(id, &err.localize(&lobby.messages)),
+        }
+    }
+
+    // Leaving drops the player's outbox, which ends the sending thread once
+    // it has sent what was left.
+    lobby.lock().unwrap().leave(id);
+    let _ = sender.join();
+    Ok(())
+}
+
+/// Sends a player's messages until the lobby lets go of their outbox.
+fn send_lines(mut stream: TcpStream, inbox: Receiver<String>) {
+    for line in inbox {
+        if writeln!(stream, "{line}").is_err() {
+            // Too slow, or gone. Hanging up ends the read in
+            // `handle_connection` too, and the player leaves the lobby.
+            let _ = stream.shutdown(Shutdown::Both);
+            return;
+        }
+    }
+}
+
+impl Lobby {
+    fn join(&mut self, name: String, outbox: Sender<String>) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let joined = self.messages.format("server-joined", &[("name", name.as_str().into())]);
+        self.broadcast(&joined);
+
+        let game = self.new_game();
+        self.players.insert(id, Player { name, outbox, game });
+        self.tell(id, &self.round_intro());
+
+        id
+    }
+
+    fn leave(&mut self, id: usize) {
+        if let Some(player) = self.players.remove(&id) {
+            let left = self.messages.format("server-left", &[("name", player.name.into())]);
+            self.broadcast(&left);
+            // The ones who are left may all have run out of attempts.
+            self.end_round_if_stuck();
+        }
+    }
+
+    fn guess(&mut self, id: usize, guess: u32) {
+        let Some(player) = self.players.get_mut(&id) else {
+            return;
+        };
+        let name = player.name.clone();
+        let outcome = player.game.guess(guess);
+        let attempts_left = player.game.attempts_left();
+        let args = [
+            ("name", name.as_str().into()),
+            ("guess", guess.into()),
+            ("round", self.round.into()),
+        ];
+
+        match outcome {
+            GuessOutcome::TooSmall => {
+                self.broadcast(&self.messages.format("server-too-small", &args));
+            }
+            GuessOutcome::TooBig => {
+                self.broadcast(&self.messages.format("server-too-big", &args));
+            }
+            GuessOutcome::Correct => {
+                self.broadcast(&self.messages.format("server-correct", &args));
+                self.next_round();
+                return;
+            }
+            GuessOutcome::OutOfRange => {
+                let message = self.messages.format(
+                    "out-of-range",
+                    &[("min", self.min.into()), ("max", self.max.into())],
+                );
+                self.tell(id, &message);
+                return;
+            }
+            GuessOutcome::GameOver => {
+                self.tell(id, &self.messages.get("server-out-of-attempts"));
+                return;
+            }
+        }
+
+        if attempts_left == 0 {
+            self.tell(id, &self.messages.get("server-out-of-attempts"));
+            self.end_round_if_stuck();
+        }
+    }
+
+    fn hint(&mut self, id: usize, kind: HintKind) {
+        let Some(player) = self.players.get_mut(&id) else {
+            return;
+        };
+
+        let message = match player.game.hint(kind) {
+            Ok(hint) => hint.localize(&self.messages),
+            Err(err) => err.localize(&self.messages),
+        };
+        self.tell(id, &message);
+    }
+
+    fn end_round_if_stuck(&mut self) {
+        let stuck = !self.players.is_empty()
+            && self
+                .players
+                .values()
+                .all(|player| player.game.status() == Status::Lost);
+
+        if stuck {
+            let message = self.messages.format("server-nobody", &[("secret", self.secret.into())]);
+            self.broadcast(&message);
+            self.next_round();
+        }
+    }
+
+    fn next_round(&mut self) {
+        self.round += 1;
+        self.secret = self.rng.gen_range(self.min..=self.max);
+
+        for id in self.players.keys().copied().collect::<Vec<_>>() {
+            let game = self.new_game();
+            self.players.get_mut(&id).unwrap().game = game;
+        }
+
+        self.broadcast(&self.round_intro());
+    }
+
+    fn new_game(&self) -> Game {
+        Game::with_secret(self.min, self.max, self.secret, self.max_attempts).with_hints(self.hints)
+    }
+
+    fn round_intro(&self) -> String {
+        self.messages.format(
+            "server-round-intro",
+            &[
+                ("round", self.round.into()),
+                ("min", self.min.into()),
+                ("max", self.max.into()),
+                ("attempts", self.max_attempts.into()),
+            ],
+        )
+    }
+
+    /// Queues a line for one player. This never blocks; a player who
+    /// can't be reached is cleaned up by their connection thread.
+    fn tell(&self, id: usize, message: &str) {
+        if let Some(player) = self.players.get(&id) {
+            let _ = player.outbox.send(message.to_string());
+        }
+    }
+
+    fn broadcast(&self, message: &str) {
+        println!("{message}");
+        for player in self.players.values() {
+            let _ = player.outbox.send(message.to_string());
+        }
+    }
+}
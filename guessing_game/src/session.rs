@@ -0,0 +1,297 @@
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+use crate::data;
+use crate::game::{Game, GuessOutcome};
+use crate::hint::{Hint, HintError, HintKind};
+use crate::input::{self, Command, InputError};
+
+/// Bumped whenever the log format changes in a way old logs can't follow.
+pub const LOG_VERSION: u32 = 2;
+
+/// What happened in response to one line of input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    Guess { guess: u32, outcome: GuessOutcome },
+    Hint {
+        kind: HintKind,
+        result: Result<Hint, HintError>,
+    },
+    Help,
+    Quit,
+    Invalid { error: InputError },
+    EndOfInput,
+    /// No guess came in the time one is allowed; the attempt is lost.
+    TimedOut,
+    /// The time for the whole game ran out, which loses it.
+    OutOfTime,
+}
+
+/// Everything needed to set the game up again: the first line of a log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    pub version: u32,
+    pub seed: u64,
+    pub min: u32,
+    pub max: u32,
+    pub max_attempts: u32,
+    /// How many paid hints the player may ask for.
+    #[serde(default)]
+    pub hints: u32,
+    /// Milliseconds since the Unix epoch.
+    pub started_at: u64,
+}
+
+impl Header {
+    pub fn new(seed: u64, min: u32, max: u32, max_attempts: u32, hints: u32) -> Header {
+        Header {
+            version: LOG_VERSION,
+            seed,
+            min,
+            max,
+            max_attempts,
+            hints,
+            started_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_millis() as u64),
+        }
+    }
+
+    /// Where a session log goes by default:
+    /// `<data dir>/sessions/<started at>-<seed>.jsonl`.
+    pub fn default_log_path(&self) -> Option<PathBuf> {
+        let file = format!("{}-{}.jsonl", self.started_at, self.seed);
+        Some(data::data_dir()?.join("sessions").join(file))
+    }
+}
+
+/// One line of a log after the header.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Milliseconds since the session started.
+    pub at_ms: u64,
+    /// The raw line, or `None` for events that no line caused: the end of
+    /// the input and running out of time.
+    pub input: Option<String>,
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+/// Writes a session as JSON Lines: the header, then one entry per input.
+/// Every line is flushed at once, so a crashed session still leaves a log.
+pub struct Recorder {
+    path: PathBuf,
+    out: BufWriter<File>,
+}
+
+impl Recorder {
+    pub fn create(path: &Path, header: &Header) -> io::Result<Recorder> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut recorder = Recorder {
+            path: path.to_path_buf(),
+            out: BufWriter::new(File::create(path)?),
+        };
+        recorder.write(header)?;
+
+        Ok(recorder)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn write(&mut self, value: &impl Serialize) -> io::Result<()> {
+        serde_json::to_writer(&mut self.out, value)?;
+        writeln!(self.out)?;
+        self.out.flush()
+    }
+}
+
+/// A game together with the way its input is understood and recorded.
+///
+/// Every front end feeds raw lines through `handle_line`, which is what
+/// makes a recorded session replayable.
+pub struct Session {
+    game: Game,
+    started: Instant,
+    recorder: Option<Recorder>,
+    ended: bool,
+}
+
+impl Session {
+    /// Sets up the game described by `header`. The secret is the first
+    /// number drawn from a generator seeded with `header.seed`.
+    pub fn start(header: &Header, recorder: Option<Recorder>) -> Session {
+        let mut rng = StdRng::seed_from_u64(header.seed);
+        let game = Game::new(header.min, header.max, header.max_attempts, &mut rng)
+            .with_hints(header.hints);
+
+        Session {
+            game,
+            started: Instant::now(),
+            recorder,
+            ended: false,
+        }
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn started(&self) -> Instant {
+        self.started
+    }
+
+    /// Whether the game is won or lost, or the player left.
+    pub fn is_over(&self) -> bool {
+        self.ended || self.game.is_over()
+    }
+
+    pub fn handle_line(&mut self, line: &str) -> Event {
+        let event = match input::parse(line) {
+            Ok(Command::Guess(guess)) => Event::Guess {
+                guess,
+                outcome: self.game.guess(guess),
+            },
+            Ok(Command::Hint(kind)) => Event::Hint {
+                kind,
+                result: self.game.hint(kind),
+            },
+            Ok(Command::Help) => Event::Help,
+            Ok(Command::Quit) => {
+                self.ended = true;
+                Event::Quit
+            }
+            Err(error) => Event::Invalid { error },
+        };
+
+        self.record(Some(line), &event);
+        event
+    }
+
+    pub fn end_of_input(&mut self) -> Event {
+        self.ended = true;
+        let event = Event::EndOfInput;
+        self.record(None, &event);
+        event
+    }
+
+    pub fn time_out(&mut self) -> Event {
+        self.game.waste_attempt();
+        let event = Event::TimedOut;
+        self.record(None, &event);
+        event
+    }
+
+    pub fn out_of_time(&mut self) -> Event {
+        self.game.forfeit();
+        let event = Event::OutOfTime;
+        self.record(None, &event);
+        event
+    }
+
+    fn record(&mut self, input: Option<&str>, event: &Event) {
+        let Some(recorder) = &mut self.recorder else {
+            return;
+        };
+
+        let entry = Entry {
+            at_ms: self.started.elapsed().as_millis() as u64,
+            // Keep what was typed, but not the newline that ended it.
+            input: input.map(|line| line.trim_end_matches(['\n', '\r']).to_string()),
+            event: event.clone(),
+        };
+
+        // Losing the log shouldn't end the game; warn once and stop logging.
+        if let Err(err) = recorder.write(&entry) {
+            eprintln!(
+                "Could not write the session log {}: {err}",
+                recorder.path().display()
+            );
+            self.recorder = None;
+        }
+    }
+}
+
+/// An entry whose outcome came out differently when replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// Position of the entry in the log, starting at 1.
+    pub index: usize,
+    pub input: Option<String>,
+    pub recorded: Event,
+    pub replayed: Event,
+}
+
+/// The result of running a recorded session again.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    pub header: Header,
+    pub entries: usize,
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// Re-runs a session log against the current game logic.
+pub fn replay(log: impl BufRead) -> Result<Replay, Box<dyn Error>> {
+    let mut lines = log.lines();
+
+    let header: Header = match lines.next() {
+        Some(line) => serde_json::from_str(&line?)
+            .map_err(|err| format!("the log doesn't start with a session header: {err}"))?,
+        None => return Err("the log is empty".into()),
+    };
+    if header.version != LOG_VERSION {
+        return Err(format!(
+            "the log has version {}, but this build reads version {LOG_VERSION}",
+            header.version
+        )
+        .into());
+    }
+
+    let mut session = Session::start(&header, None);
+    let mut entries = 0;
+    let mut mismatches = Vec::new();
+
+    for (index, line) in lines.enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: Entry = serde_json::from_str(&line)
+            .map_err(|err| format!("entry {} is not valid: {err}", index + 1))?;
+
+        let replayed = match (&entry.input, &entry.event) {
+            (Some(input), _) => session.handle_line(input),
+            (None, Event::TimedOut) => session.time_out(),
+            (None, Event::OutOfTime) => session.out_of_time(),
+            (None, _) => session.end_of_input(),
+        };
+
+        entries += 1;
+        if replayed != entry.event {
+            mismatches.push(Mismatch {
+                index: index + 1,
+                input: entry.input,
+                recorded: entry.event,
+                replayed,
+            });
+        }
+    }
+
+    Ok(Replay {
+        header,
+        entries,
+        mismatches,
+    })
+}
@@ -0,0 +1,47 @@
+use rand::Rng;
+
+use crate::config::Config;
+use crate::game::{Game, Status};
+use crate::strategy::{self, StrategyKind};
+
+/// How a strategy did over many games.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Summary {
+    pub games: u32,
+    pub wins: u32,
+    pub total_attempts: u64,
+    pub max_attempts: u32,
+}
+
+impl Summary {
+    pub fn average_attempts(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.total_attempts as f64 / f64::from(self.games)
+        }
+    }
+}
+
+/// Plays `games` games of the configured size, with a fresh secret each,
+/// all drawn from `rng`.
+pub fn simulate(kind: StrategyKind, config: &Config, games: u32, rng: &mut impl Rng) -> Summary {
+    let (min, max) = (config.min, config.max);
+    let mut summary = Summary::default();
+
+    for _ in 0..games {
+        let mut game = Game::new(min, max, config.max_attempts, rng);
+        let mut strategy = kind.build(min, max, rng);
+
+        strategy::autoplay(&mut game, strategy.as_mut(), |_, _| {});
+
+        summary.games += 1;
+        if game.status() == Status::Won {
+            summary.wins += 1;
+        }
+        summary.total_attempts += u64::from(game.attempts());
+        summary.max_attempts = summary.max_attempts.max(game.attempts());
+    }
+
+    summary
+}
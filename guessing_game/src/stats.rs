@@ -0,0 +1,294 @@
+//! Statistics over every game in the history, for `guessing_game stats`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::history::{Outcome, Record};
+use crate::leaderboard::DIFFICULTIES;
+use crate::strategy::binary_search_bound;
+
+/// How `stats` prints its report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Format::Table => "table",
+            Format::Csv => "csv",
+            Format::Json => "json",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format '{s}' (expected table, csv or json)")),
+        }
+    }
+}
+
+/// The guess most games started with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FirstGuess {
+    pub guess: u32,
+    pub games: u32,
+}
+
+/// The numbers for one group of games: one difficulty, or all of them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Group {
+    pub difficulty: String,
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub abandoned: u32,
+    /// Wins per game.
+    pub win_rate: Option<f64>,
+    /// How many wins took each number of attempts.
+    pub attempts: BTreeMap<u32, u32>,
+    /// Attempts per win.
+    pub average_attempts: Option<f64>,
+    /// The most attempts a binary search could need, averaged over the
+    /// ranges of the wins.
+    pub binary_search_bound: Option<f64>,
+    /// Wins that took no more attempts than a binary search could need.
+    pub within_bound: u32,
+    /// Milliseconds per game, won or not.
+    pub average_time_ms: Option<f64>,
+    pub most_common_first_guess: Option<FirstGuess>,
+}
+
+impl Group {
+    fn new(difficulty: &str, records: &[&Record]) -> Group {
+        let count = |outcome| records.iter().filter(|record| record.outcome == outcome).count();
+        let wins: Vec<&Record> = records
+            .iter()
+            .copied()
+            .filter(|record| record.outcome == Outcome::Won)
+            .collect();
+
+        let mut attempts = BTreeMap::new();
+        for record in &wins {
+            *attempts.entry(record.attempts).or_insert(0) += 1;
+        }
+
+        let bounds: Vec<u32> = wins
+            .iter()
+            .map(|record| binary_search_bound(record.min, record.max))
+            .collect();
+        let within_bound = wins
+            .iter()
+            .zip(&bounds)
+            .filter(|(record, bound)| record.attempts <= **bound)
+            .count();
+
+        let games = records.len() as u32;
+        Group {
+            difficulty: difficulty.to_string(),
+            games,
+            wins: wins.len() as u32,
+            losses: count(Outcome::Lost) as u32,
+            abandoned: count(Outcome::Abandoned) as u32,
+            win_rate: (games > 0).then(|| wins.len() as f64 / f64::from(games)),
+            attempts,
+            average_attempts: average(wins.iter().map(|record| f64::from(record.attempts))),
+            binary_search_bound: average(bounds.iter().map(|&bound| f64::from(bound))),
+            within_bound: within_bound as u32,
+            average_time_ms: average(records.iter().map(|record| record.elapsed_ms as f64)),
+            most_common_first_guess: most_common_first_guess(records),
+        }
+    }
+}
+
+fn average(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+    (count > 0).then(|| sum / f64::from(count))
+}
+
+/// Ties go to the smaller guess, so the answer doesn't depend on the order
+/// of the history.
+fn most_common_first_guess(records: &[&Record]) -> Option<FirstGuess> {
+    let mut counts: HashMap<u32, u32> = HashMap::new();
+    for guess in records.iter().filter_map(|record| record.first_guess) {
+        *counts.entry(guess).or_insert(0) += 1;
+    }
+
+    counts
+        .into_iter()
+        .max_by_key(|&(guess, games)| (games, std::cmp::Reverse(guess)))
+        .map(|(guess, games)| FirstGuess { guess, games })
+}
+
+/// The whole report: every game together, then one group per difficulty.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub overall: Group,
+    pub by_difficulty: Vec<Group>,
+}
+
+impl Report {
+    pub fn new(records: &[Record]) -> Report {
+        let mut groups: BTreeMap<(usize, bool, &str), Vec<&Record>> = BTreeMap::new();
+        for record in records {
+            // Presets in the leaderboard's order, each followed by its blitz
+            // version; anything else comes last, alphabetically.
+            let base = record.difficulty.trim_end_matches(" blitz");
+            let rank = DIFFICULTIES
+                .iter()
+                .position(|&difficulty| difficulty == base)
+                .unwrap_or(DIFFICULTIES.len());
+            let blitz = base.len() != record.difficulty.len();
+            groups
+                .entry((rank, blitz, &record.difficulty))
+                .or_default()
+                .push(record);
+        }
+
+        Report {
+            overall: Group::new("all", &records.iter().collect::<Vec<_>>()),
+            by_difficulty: groups
+                .into_iter()
+                .map(|((_, _, difficulty), records)| Group::new(difficulty, &records))
+                .collect(),
+        }
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Table => self.table(),
+            Format::Csv => self.csv(),
+            Format::Json => {
+                let mut json = serde_json::to_string_pretty(self).expect("a report is valid JSON");
+                json.push('\n');
+                json
+            }
+        }
+    }
+
+    fn table(&self) -> String {
+        let all = &self.overall;
+        if all.games == 0 {
+            return String::from("No games recorded yet. Play one to get some statistics!\n");
+        }
+
+        let mut out = format!(
+            "{} games: {} won ({}), {} lost, {} abandoned\n",
+            all.games,
+            all.wins,
+            percent(all.win_rate),
+            all.losses,
+            all.abandoned
+        );
+        out.push_str(&format!("Average time: {} per game\n", seconds(all.average_time_ms)));
+        if let Some(first) = all.most_common_first_guess {
+            out.push_str(&format!(
+                "Most common first guess: {} ({} of {} games)\n",
+                first.guess, first.games, all.games
+            ));
+        }
+        if let (Some(attempts), Some(bound)) = (all.average_attempts, all.binary_search_bound) {
+            out.push_str(&format!(
+                "Wins took {attempts:.2} attempts on average; a binary search needs at most \
+                 {bound:.2}. {} of {} wins were within that bound.\n",
+                all.within_bound, all.wins
+            ));
+        }
+
+        out.push_str(&format!(
+            "\n{:<16} {:>6} {:>5} {:>9} {:>13} {:>14} {:>9}\n",
+            "difficulty", "games", "won", "win rate", "avg attempts", "binary search", "avg time"
+        ));
+        for group in &self.by_difficulty {
+            out.push_str(&format!(
+                "{:<16} {:>6} {:>5} {:>9} {:>13} {:>14} {:>9}\n",
+                group.difficulty,
+                group.games,
+                group.wins,
+                percent(group.win_rate),
+                decimal(group.average_attempts),
+                decimal(group.binary_search_bound),
+                seconds(group.average_time_ms),
+            ));
+        }
+
+        if !all.attempts.is_empty() {
+            out.push_str("\nAttempts to win:\n");
+            let most = all.attempts.values().copied().max().unwrap_or(1);
+            for (attempts, &wins) in &all.attempts {
+                // Scale the bars so the longest is 40 characters wide.
+                let bar = "#".repeat((wins * 40).div_ceil(most) as usize);
+                out.push_str(&format!("{attempts:>4} | {bar} {wins}\n"));
+            }
+        }
+
+        out
+    }
+
+    /// One row per group, `all` first. The attempts distribution is packed
+    /// into one column as `attempts:wins` pairs separated by `;`.
+    fn csv(&self) -> String {
+        let mut out = String::from(
+            "difficulty,games,wins,losses,abandoned,win_rate,average_attempts,\
+             binary_search_bound,within_bound,average_time_s,most_common_first_guess,\
+             attempts_distribution\n",
+        );
+
+        for group in std::iter::once(&self.overall).chain(&self.by_difficulty) {
+            let optional = |value: Option<f64>, digits: usize| {
+                value.map_or(String::new(), |value| format!("{value:.digits$}"))
+            };
+            let distribution: Vec<String> = group
+                .attempts
+                .iter()
+                .map(|(attempts, wins)| format!("{attempts}:{wins}"))
+                .collect();
+
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                group.difficulty,
+                group.games,
+                group.wins,
+                group.losses,
+                group.abandoned,
+                optional(group.win_rate, 4),
+                optional(group.average_attempts, 2),
+                optional(group.binary_search_bound, 2),
+                group.within_bound,
+                optional(group.average_time_ms.map(|ms| ms / 1000.0), 1),
+                group
+                    .most_common_first_guess
+                    .map_or(String::new(), |first| first.guess.to_string()),
+                distribution.join(";"),
+            ));
+        }
+
+        out
+    }
+}
+
+fn percent(rate: Option<f64>) -> String {
+    rate.map_or(String::from("-"), |rate| format!("{:.1}%", rate * 100.0))
+}
+
+fn decimal(value: Option<f64>) -> String {
+    value.map_or(String::from("-"), |value| format!("{value:.2}"))
+}
+
+fn seconds(ms: Option<f64>) -> String {
+    ms.map_or(String::from("-"), |ms| format!("{:.1}s", ms / 1000.0))
+}
@@ -0,0 +1,177 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::game::{Game, GuessOutcome};
+
+/// A way of playing the game automatically.
+///
+/// A strategy only ever learns how its guesses compared to the secret, the
+/// same `Ordering` a human reads as "Too small!" or "Too big!".
+pub trait Strategy {
+    fn next_guess(&mut self) -> u32;
+
+    /// Learns how `guess` compared to the secret: `Less` means the guess
+    /// was too small.
+    fn observe(&mut self, guess: u32, ordering: Ordering);
+}
+
+/// Always guesses the middle of what is left, halving the range each time.
+pub struct BinarySearch {
+    low: u32,
+    high: u32,
+}
+
+impl BinarySearch {
+    pub fn new(min: u32, max: u32) -> BinarySearch {
+        BinarySearch { low: min, high: max }
+    }
+}
+
+impl Strategy for BinarySearch {
+    fn next_guess(&mut self) -> u32 {
+        self.low + (self.high - self.low) / 2
+    }
+
+    fn observe(&mut self, guess: u32, ordering: Ordering) {
+        narrow(&mut self.low, &mut self.high, guess, ordering);
+    }
+}
+
+/// Guesses anywhere in what is left. It never wastes a guess, but it
+/// doesn't make the most of one either.
+pub struct Random {
+    low: u32,
+    high: u32,
+    rng: StdRng,
+}
+
+impl Random {
+    pub fn new(min: u32, max: u32, rng: StdRng) -> Random {
+        Random { low: min, high: max, rng }
+    }
+}
+
+impl Strategy for Random {
+    fn next_guess(&mut self) -> u32 {
+        self.rng.gen_range(self.low..=self.high)
+    }
+
+    fn observe(&mut self, guess: u32, ordering: Ordering) {
+        narrow(&mut self.low, &mut self.high, guess, ordering);
+    }
+}
+
+/// Aims for the middle like a binary search, but misses it by up to a
+/// quarter of the remaining range, the way people eyeball "about half".
+pub struct HumanLike {
+    low: u32,
+    high: u32,
+    rng: StdRng,
+}
+
+impl HumanLike {
+    pub fn new(min: u32, max: u32, rng: StdRng) -> HumanLike {
+        HumanLike { low: min, high: max, rng }
+    }
+}
+
+impl Strategy for HumanLike {
+    fn next_guess(&mut self) -> u32 {
+        let middle = self.low + (self.high - self.low) / 2;
+        let noise = (self.high - self.low) / 4;
+        let guess = self
+            .rng
+            .gen_range(middle.saturating_sub(noise)..=middle.saturating_add(noise));
+        guess.clamp(self.low, self.high)
+    }
+
+    fn observe(&mut self, guess: u32, ordering: Ordering) {
+        narrow(&mut self.low, &mut self.high, guess, ordering);
+    }
+}
+
+fn narrow(low: &mut u32, high: &mut u32, guess: u32, ordering: Ordering) {
+    match ordering {
+        Ordering::Less => *low = (*low).max(guess + 1),
+        Ordering::Greater => *high = (*high).min(guess - 1),
+        Ordering::Equal => {
+            *low = guess;
+            *high = guess;
+        }
+    }
+}
+
+/// The strategies that can be picked from the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrategyKind {
+    BinarySearch,
+    Random,
+    HumanLike,
+}
+
+impl StrategyKind {
+    pub fn build(self, min: u32, max: u32, rng: &mut impl Rng) -> Box<dyn Strategy> {
+        // Each strategy gets its own generator, derived from the game's, so a
+        // seeded simulation is reproducible as a whole.
+        let rng = StdRng::seed_from_u64(rng.r#gen());
+        match self {
+            StrategyKind::BinarySearch => Box::new(BinarySearch::new(min, max)),
+            StrategyKind::Random => Box::new(Random::new(min, max, rng)),
+            StrategyKind::HumanLike => Box::new(HumanLike::new(min, max, rng)),
+        }
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            StrategyKind::BinarySearch => "binary",
+            StrategyKind::Random => "random",
+            StrategyKind::HumanLike => "human",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for StrategyKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "binary" => Ok(StrategyKind::BinarySearch),
+            "random" => Ok(StrategyKind::Random),
+            "human" => Ok(StrategyKind::HumanLike),
+            _ => Err(format!("unknown strategy '{s}' (expected binary, random or human)")),
+        }
+    }
+}
+
+/// Lets `strategy` play `game` until it is over, calling `on_guess` after
+/// every guess.
+pub fn autoplay(
+    game: &mut Game,
+    strategy: &mut dyn Strategy,
+    mut on_guess: impl FnMut(u32, GuessOutcome),
+) {
+    while !game.is_over() {
+        let guess = strategy.next_guess();
+        let outcome = game.guess(guess);
+
+        on_guess(guess, outcome);
+
+        if let Some(ordering) = outcome.ordering() {
+            strategy.observe(guess, ordering);
+        }
+    }
+}
+
+/// The most guesses a binary search needs to find any number in
+/// `min..=max`: one per bit of the size of the range.
+pub fn binary_search_bound(min: u32, max: u32) -> u32 {
+    let size = u64::from(max - min) + 1;
+    u64::BITS - size.leading_zeros()
+}
@@ -0,0 +1,267 @@
+//! A full-screen front end for the game, drawn with `ratatui`.
+//!
+//! It drives the same `Game` as the line mode, so the rules are identical;
+//! only the way guesses are typed and answered changes.
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use ratatui::DefaultTerminal;
+use ratatui::crossterm::event::{self, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, Paragraph};
+use ratatui::Frame;
+
+use crate::game::{Game, GuessOutcome, Status};
+use crate::i18n::{Localize, Messages};
+use crate::session::{Event, Session};
+
+/// How often the screen is redrawn when nothing happens, to keep the
+/// timer moving.
+const TICK: Duration = Duration::from_millis(200);
+
+struct App<'a> {
+    session: &'a mut Session,
+    messages: &'a Messages,
+    input: String,
+    message: String,
+    history: Vec<(u32, GuessOutcome)>,
+    started: Instant,
+    finished: Option<Duration>,
+    quit: bool,
+}
+
+/// Plays the session full screen until it is over or the player leaves.
+/// Returns how long the game took.
+pub fn run(session: &mut Session, messages: &Messages) -> io::Result<Duration> {
+    let mut terminal = ratatui::init();
+    let result = App::new(session, messages).run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+impl<'a> App<'a> {
+    fn new(session: &'a mut Session, messages: &'a Messages) -> App<'a> {
+        App {
+            started: session.started(),
+            session,
+            messages,
+            input: String::new(),
+            message: messages.get("tui-prompt"),
+            history: Vec::new(),
+            finished: None,
+            quit: false,
+        }
+    }
+
+    fn game(&self) -> &Game {
+        self.session.game()
+    }
+
+    fn run(mut self, terminal: &mut DefaultTerminal) -> io::Result<Duration> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if !event::poll(TICK)? {
+                continue;
+            }
+            if let event::Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                let ctrl_c =
+                    key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+
+                if self.finished.is_some() {
+                    self.quit = true;
+                    continue;
+                }
+                // Giving up is logged as if "quit" was typed, so the session
+                // replays the same way.
+                if ctrl_c || key.code == KeyCode::Esc {
+                    self.session.handle_line("quit");
+                    self.quit = true;
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char(c) => self.input.push(c),
+                    KeyCode::Backspace => {
+                        self.input.pop();
+                    }
+                    KeyCode::Enter => self.submit(),
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(self.elapsed())
+    }
+
+    fn submit(&mut self) {
+        let line = std::mem::take(&mut self.input);
+
+        let messages = self.messages;
+        self.message = match self.session.handle_line(&line) {
+            Event::Guess { guess, outcome } => {
+                if outcome.ordering().is_some() {
+                    self.history.push((guess, outcome));
+                }
+                let guess = [("guess", guess.into())];
+                match outcome {
+                    GuessOutcome::TooSmall => messages.format("tui-too-small", &guess),
+                    GuessOutcome::TooBig => messages.format("tui-too-big", &guess),
+                    GuessOutcome::Correct => messages.format("tui-correct", &guess),
+                    GuessOutcome::OutOfRange => messages.format(
+                        "out-of-range",
+                        &[("min", self.game().min().into()), ("max", self.game().max().into())],
+                    ),
+                    GuessOutcome::GameOver => messages.get("game-over"),
+                }
+            }
+            Event::Hint { result, .. } => match result {
+                Ok(hint) => hint.localize(messages),
+                Err(err) => err.localize(messages),
+            },
+            Event::Help => messages.get("tui-help"),
+            // The terminal UI has no clock, so it never times out.
+            Event::Quit | Event::EndOfInput | Event::TimedOut | Event::OutOfTime => {
+                self.quit = true;
+                return;
+            }
+            Event::Invalid { error } => error.localize(messages),
+        };
+
+        match self.game().status() {
+            Status::InProgress => {}
+            Status::Won => {
+                self.finished = Some(self.started.elapsed());
+                self.message =
+                    messages.format("tui-win", &[("attempts", self.game().attempts().into())]);
+            }
+            Status::Lost => {
+                self.finished = Some(self.started.elapsed());
+                self.message =
+                    messages.format("tui-lose", &[("secret", self.game().secret().into())]);
+            }
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.finished.unwrap_or_else(|| self.started.elapsed())
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [title, range, body, message, input] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(4),
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(3),
+        ])
+        .areas(frame.area());
+        let [history, status] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(body);
+
+        frame.render_widget(
+            Line::from(self.messages.format(
+                "tui-title",
+                &[("min", self.game().min().into()), ("max", self.game().max().into())],
+            ))
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+            title,
+        );
+        self.draw_number_line(frame, range);
+        self.draw_history(frame, history);
+        self.draw_status(frame, status);
+        frame.render_widget(Line::from(self.message.as_str()), message);
+        frame.render_widget(
+            Paragraph::new(format!("> {}", self.input))
+                .block(Block::bordered().title(self.messages.get("tui-guess"))),
+            input,
+        );
+    }
+
+    /// One cell per column of the screen, shaded where the secret can
+    /// still be.
+    fn draw_number_line(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered().title(self.messages.get("tui-still-possible"));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let width = u64::from(inner.width.max(2));
+        let (min, max) = (u64::from(self.game().min()), u64::from(self.game().max()));
+        let (low, high) = self.game().remaining();
+        let (low, high) = (u64::from(low), u64::from(high));
+
+        let cells: Vec<Span> = (0..width)
+            .map(|column| {
+                let number = min + column * (max - min) / (width - 1);
+                // A column stands for a run of numbers on big ranges; it is
+                // shaded if any of them is still possible.
+                let last = (min + (column + 1) * (max - min) / (width - 1))
+                    .saturating_sub(1)
+                    .max(number);
+                if number <= high && last >= low {
+                    Span::styled("█", Style::default().fg(Color::Green))
+                } else {
+                    Span::styled("░", Style::default().fg(Color::DarkGray))
+                }
+            })
+            .collect();
+
+        let side = (inner.width as usize / 2).saturating_sub(4);
+        let labels = format!("{min:<side$}{low}-{high}{max:>side$}");
+
+        frame.render_widget(
+            Paragraph::new(vec![Line::from(cells), Line::from(labels)]),
+            inner,
+        );
+    }
+
+    fn draw_history(&self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .history
+            .iter()
+            .rev()
+            .map(|(guess, outcome)| {
+                let (answer, color) = match outcome {
+                    GuessOutcome::TooSmall => ("tui-answer-too-small", Color::Yellow),
+                    GuessOutcome::TooBig => ("tui-answer-too-big", Color::Red),
+                    _ => ("tui-answer-correct", Color::Green),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{guess:>10}  ")),
+                    Span::styled(self.messages.get(answer), Style::default().fg(color)),
+                ]))
+            })
+            .collect();
+
+        let block = Block::bordered().title(self.messages.get("tui-guesses"));
+        frame.render_widget(List::new(items).block(block), area);
+    }
+
+    fn draw_status(&self, frame: &mut Frame, area: Rect) {
+        let messages = self.messages;
+        let elapsed = self.elapsed().as_secs();
+        let time = format!("{:02}:{:02}", elapsed / 60, elapsed % 60);
+        let lines = vec![
+            Line::from(messages.format(
+                "tui-attempts",
+                &[
+                    ("attempts", self.game().attempts().into()),
+                    ("max", self.game().max_attempts().into()),
+                ],
+            )),
+            Line::from(messages.format("tui-hints", &[("hints", self.game().hints_left().into())])),
+            Line::from(messages.format("tui-time", &[("time", time.into())])),
+        ];
+
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(messages.get("tui-status"))),
+            area,
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Guessing a word instead of a number.
+//!
+//! `String` implements `Ord` too, comparing alphabetically (strictly, by
+//! Unicode code point), so the game is the same `Game`, as `Game<Word>`: a
+//! guess is either before the secret, after it, or the secret itself. A
+//! `Word` only decides what "before" and "after" mean.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// The list used when the player doesn't bring their own.
+pub const BUNDLED: &str = include_str!("words.txt");
+
+/// How words are brought into the same form before they are compared.
+///
+/// The same text can be written with different code points: "é" is one
+/// character in "Éclair" typed on most keyboards, but two ("e" and a
+/// combining accent) when it comes from some files. Normalizing makes them
+/// equal. Comparing code points also puts "éclair" after "zebra", which is
+/// why accents are dropped by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Compare the text exactly as typed.
+    None,
+    /// Canonical composition: "e" + accent becomes "é".
+    Nfc,
+    /// Compatibility composition: also turns "ﬁ" into "fi", "①" into "1".
+    Nfkc,
+    /// Compatibility decomposition without the accents: "é" becomes "e".
+    StripAccents,
+}
+
+impl fmt::Display for Normalization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Normalization::None => "none",
+            Normalization::Nfc => "nfc",
+            Normalization::Nfkc => "nfkc",
+            Normalization::StripAccents => "strip-accents",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Normalization {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Normalization::None),
+            "nfc" => Ok(Normalization::Nfc),
+            "nfkc" => Ok(Normalization::Nfkc),
+            "strip-accents" => Ok(Normalization::StripAccents),
+            _ => Err(format!(
+                "unknown normalization '{s}' (expected none, nfc, nfkc or strip-accents)"
+            )),
+        }
+    }
+}
+
+/// The rules for comparing two words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Collation {
+    pub fold_case: bool,
+    pub normalization: Normalization,
+}
+
+impl Default for Collation {
+    fn default() -> Self {
+        Collation {
+            fold_case: true,
+            normalization: Normalization::StripAccents,
+        }
+    }
+}
+
+impl Collation {
+    /// The form of `text` that is actually compared.
+    pub fn key(&self, text: &str) -> String {
+        let text = text.trim();
+        let normalized: String = match self.normalization {
+            Normalization::None => text.to_string(),
+            Normalization::Nfc => text.nfc().collect(),
+            Normalization::Nfkc => text.nfkc().collect(),
+            Normalization::StripAccents => text.nfkd().filter(|c| !is_combining_mark(*c)).collect(),
+        };
+
+        if self.fold_case {
+            normalized.to_lowercase()
+        } else {
+            normalized
+        }
+    }
+
+    pub fn word(&self, text: &str) -> Word {
+        Word {
+            text: text.trim().to_string(),
+            key: self.key(text),
+        }
+    }
+}
+
+/// A word as typed, ordered by its collation key.
+///
+/// Two words are equal when their keys are, so with the default collation
+/// "Éclair" and "eclair" are the same word.
+#[derive(Debug, Clone)]
+pub struct Word {
+    text: String,
+    key: String,
+}
+
+impl Word {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl PartialEq for Word {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Word {}
+
+impl PartialOrd for Word {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Word {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// Reads a word list: one word per line, skipping blank lines and lines
+/// starting with `#`.
+pub fn parse_list(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect()
+}
+
+pub fn load_list(path: &Path) -> io::Result<Vec<String>> {
+    Ok(parse_list(&fs::read_to_string(path)?))
+}
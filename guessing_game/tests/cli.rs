@@ -0,0 +1,299 @@
+//! Plays whole games through the binary, the way a player would: lines go
+//! in on stdin and the transcript that comes out on stdout is checked word
+//! for word.
+//!
+//! With `--seed 7` the secret between 1 and 100 is 42.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Child, Command, Output, Stdio};
+
+use tempfile::TempDir;
+
+/// Starts the game with `args`. Anything the game saves goes to
+/// `data_dir`, never to the real data directory.
+fn spawn(data_dir: &Path, args: &[&str]) -> Child {
+    Command::new(env!("CARGO_BIN_EXE_guessing_game"))
+        .args(args)
+        .env("GUESSING_GAME_DATA_DIR", data_dir)
+        .env_remove("GUESSING_GAME_SEED")
+        // The transcripts below are the English ones.
+        .env("LANG", "C")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("the binary starts")
+}
+
+/// Runs the game with `args` in `data_dir`, feeds it `input` and closes
+/// stdin.
+fn run_in(data_dir: &Path, args: &[&str], input: &str) -> Output {
+    let mut child = spawn(data_dir, args);
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .expect("the game reads its input");
+
+    child.wait_with_output().expect("the game finishes")
+}
+
+/// Runs the game with `args` and a stdin that stays open but never gets a
+/// line, like a player who walked away from the keyboard.
+fn run_idle(args: &[&str]) -> Output {
+    let data_dir = TempDir::new().unwrap();
+    let mut child = spawn(data_dir.path(), args);
+
+    // `wait_with_output` would close stdin; holding on to it keeps it open.
+    let _stdin = child.stdin.take();
+    child.wait_with_output().expect("the game finishes")
+}
+
+fn run(args: &[&str], input: &str) -> Output {
+    let data_dir = TempDir::new().unwrap();
+    run_in(data_dir.path(), args, input)
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).expect("the transcript is UTF-8")
+}
+
+#[test]
+fn narrowing_down_wins() {
+    let output = run(&["--seed", "7"], "50\n25\n42\n");
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "\
+Guess the number!
+I'm thinking of a number between 1 and 100. You have 10 attempts.
+Please input your guess!
+You guessed: 50
+Too big!
+Please input your guess!
+You guessed: 25
+Too small!
+Please input your guess!
+You guessed: 42
+You win!
+"
+    );
+}
+
+#[test]
+fn guess_time_alone_is_shown_in_the_prompt() {
+    let output = run(&["--seed", "7", "--guess-time", "30"], "50\n42\n");
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "\
+Guess the number!
+I'm thinking of a number between 1 and 100. You have 10 attempts.
+Each guess must come within 30 seconds, or the attempt is lost.
+Please input your guess! (30 seconds for this guess)
+You guessed: 50
+Too big!
+Please input your guess! (30 seconds for this guess)
+You guessed: 42
+You win!
+Your score: 2 (2 for the attempts, 0 for the time and hints). Lower is better.
+"
+    );
+}
+
+#[test]
+fn a_slow_guess_loses_its_attempt() {
+    let output = run_idle(&["--seed", "7", "--guess-time", "1", "--max-attempts", "2"]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "\
+Guess the number!
+I'm thinking of a number between 1 and 100. You have 2 attempts.
+Each guess must come within 1 second, or the attempt is lost.
+Please input your guess! (1 second for this guess)
+
+Too slow! That attempt is lost; 1 attempt left.
+Please input your guess! (1 second for this guess)
+
+Too slow! That attempt is lost; 0 attempts left.
+You lose! The number was 42.
+"
+    );
+}
+
+#[test]
+fn the_time_limit_ends_the_game() {
+    let output = run_idle(&["--seed", "7", "--time-limit", "1"]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "\
+Guess the number!
+I'm thinking of a number between 1 and 100. You have 10 attempts.
+You have 1 second for the whole game.
+Please input your guess! (1 second left)
+
+Time's up!
+You lose! The number was 42.
+"
+    );
+}
+
+#[test]
+fn garbage_is_explained_and_costs_nothing() {
+    let output = run(
+        &["--seed", "7", "--max-attempts", "2"],
+        "abc\n\n-5\n99999999999\nhint foo\n150\n42\n",
+    );
+
+    // Only 42 counted as an attempt, so two attempts were plenty.
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "\
+Guess the number!
+I'm thinking of a number between 1 and 100. You have 2 attempts.
+Please input your guess!
+'abc' is not a number. Type 'help' for the commands.
+Please input your guess!
+Please type a number (or 'help').
+Please input your guess!
+'-5' doesn't fit in a u32 (0 to 4294967295).
+Please input your guess!
+'99999999999' doesn't fit in a u32 (0 to 4294967295).
+Please input your guess!
+'hint foo' is not a hint. Try 'hint', 'hint parity', 'hint div 3' or 'hint near'.
+Please input your guess!
+You guessed: 150
+Your guess must be between 1 and 100.
+Please input your guess!
+You guessed: 42
+You win!
+"
+    );
+}
+
+#[test]
+fn end_of_input_reveals_the_secret() {
+    let output = run(&["--seed", "7"], "50\n");
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "\
+Guess the number!
+I'm thinking of a number between 1 and 100. You have 10 attempts.
+Please input your guess!
+You guessed: 50
+Too big!
+Please input your guess!
+
+No more input. The number was 42.
+"
+    );
+}
+
+#[test]
+fn running_out_of_attempts_loses() {
+    // The last line is never read: the game is over before it.
+    let output = run(&["--seed", "7", "--max-attempts", "3"], "1\n2\n3\n42\n");
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "\
+Guess the number!
+I'm thinking of a number between 1 and 100. You have 3 attempts.
+Please input your guess!
+You guessed: 1
+Too small!
+Please input your guess!
+You guessed: 2
+Too small!
+Please input your guess!
+You guessed: 3
+Too small!
+You lose! The number was 42.
+"
+    );
+}
+
+#[test]
+fn quitting_gives_up() {
+    let output = run(&["--seed", "7"], "quit\n");
+
+    assert_eq!(
+        stdout(&output),
+        "\
+Guess the number!
+I'm thinking of a number between 1 and 100. You have 10 attempts.
+Please input your guess!
+You gave up! The number was 42.
+"
+    );
+}
+
+#[test]
+fn reports_speak_the_chosen_language() {
+    let output = run(&["--simulate", "10", "--seed", "7", "--lang", "vi"], "");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output).lines().next(),
+        Some("Chiến lược binary, 10 ván từ 1 đến 100, mỗi ván 10 lượt đoán:")
+    );
+
+    // Why a guess doesn't parse is put into words by the catalog too.
+    let output = run(
+        &["--domain", "decimal", "--precision", "1", "--seed", "7", "--lang", "vi"],
+        "1.25\n",
+    );
+    assert!(
+        stdout(&output)
+            .contains("'1.25' không phải là số đoán hợp lệ: có hơn 1 chữ số sau dấu thập phân.")
+    );
+}
+
+#[test]
+fn a_win_reaches_the_leaderboard_and_the_stats() {
+    let data_dir = TempDir::new().unwrap();
+    run_in(data_dir.path(), &["--seed", "7", "--name", "ferris"], "50\n42\n");
+
+    let leaderboard = run_in(data_dir.path(), &["leaderboard"], "");
+    assert_eq!(
+        stdout(&leaderboard).lines().take(1).collect::<Vec<_>>(),
+        ["== normal =="]
+    );
+    assert!(stdout(&leaderboard).contains("1. ferris             2 attempts"));
+    let flag = run_in(data_dir.path(), &["--top", "1", "--leaderboard"], "");
+    assert_eq!(stdout(&flag), stdout(&leaderboard));
+
+    let stats = run_in(data_dir.path(), &["stats", "--format", "csv"], "");
+    assert!(
+        stdout(&stats)
+            .lines()
+            .any(|line| line.starts_with("normal,1,1,0,0,1.0000,2.00,7.00,1,"))
+    );
+
+    let stats = run_in(data_dir.path(), &["stats", "--format", "json"], "");
+    let json: serde_json::Value = serde_json::from_str(&stdout(&stats)).unwrap();
+    assert_eq!(json["overall"]["win_rate"], 1.0);
+    assert_eq!(json["by_difficulty"][0]["win_rate"], 1.0);
+}
+
+#[test]
+fn bad_arguments_exit_with_usage_errors() {
+    let output = run(&["--min", "10", "--max", "5"], "");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "--min (10) must not be greater than --max (5)\n"
+    );
+}
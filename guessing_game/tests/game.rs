@@ -0,0 +1,68 @@
+use guessing_game::{Game, GuessOutcome, Status};
+
+#[test]
+fn out_of_range_guesses_are_free() {
+    let mut game = Game::with_secret(1, 100, 42, 3);
+
+    assert_eq!(game.guess(0), GuessOutcome::OutOfRange);
+    assert_eq!(game.guess(101), GuessOutcome::OutOfRange);
+    assert_eq!(game.attempts(), 0);
+    assert_eq!(game.attempts_left(), 3);
+    assert_eq!(game.first_guess(), None);
+    assert_eq!(game.remaining(), (1, 100));
+}
+
+#[test]
+fn the_last_attempt_loses() {
+    let mut game = Game::with_secret(1, 100, 42, 2);
+
+    assert_eq!(game.guess(10), GuessOutcome::TooSmall);
+    assert_eq!(game.status(), Status::InProgress);
+    assert_eq!(game.guess(90), GuessOutcome::TooBig);
+    assert_eq!(game.status(), Status::Lost);
+    assert!(game.is_over());
+    assert_eq!(game.attempts_left(), 0);
+}
+
+#[test]
+fn a_correct_last_guess_still_wins() {
+    let mut game = Game::with_secret(1, 100, 42, 1);
+
+    assert_eq!(game.guess(42), GuessOutcome::Correct);
+    assert_eq!(game.status(), Status::Won);
+}
+
+#[test]
+fn nothing_counts_once_the_game_is_over() {
+    let mut won = Game::with_secret(1, 100, 42, 5);
+    won.guess(42);
+    let mut lost = Game::with_secret(1, 100, 42, 1);
+    lost.guess(1);
+
+    for game in [&mut won, &mut lost] {
+        let attempts = game.attempts();
+        for guess in [0, 1, 42, 100, 101] {
+            assert_eq!(game.guess(guess), GuessOutcome::GameOver);
+        }
+        assert_eq!(game.attempts(), attempts);
+    }
+    assert_eq!(won.status(), Status::Won);
+    assert_eq!(lost.status(), Status::Lost);
+}
+
+#[test]
+fn remaining_narrows_with_every_answer() {
+    let mut game = Game::with_secret(1, 100, 42, 10);
+
+    game.guess(50);
+    assert_eq!(game.remaining(), (1, 49));
+    game.guess(25);
+    assert_eq!(game.remaining(), (26, 49));
+    // A guess outside what is left says nothing new, and mustn't widen it.
+    game.guess(75);
+    assert_eq!(game.remaining(), (26, 49));
+    game.guess(10);
+    assert_eq!(game.remaining(), (26, 49));
+    game.guess(42);
+    assert_eq!(game.remaining(), (42, 42));
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use guessing_game::hint::NEAR_DISTANCE;
+use guessing_game::{Game, Hint, HintError, HintKind, Score};
+
+fn game(secret: u32, max_attempts: u32, hints: u32) -> Game {
+    Game::with_secret(1, 100, secret, max_attempts).with_hints(hints)
+}
+
+#[test]
+fn a_paid_hint_costs_an_attempt_and_a_hint() {
+    let mut game = game(42, 10, 2);
+
+    assert_eq!(game.hint(HintKind::Parity), Ok(Hint::Even));
+    assert_eq!(game.attempts(), 1);
+    assert_eq!(game.hints_used(), 1);
+    assert_eq!(game.hints_left(), 1);
+}
+
+#[test]
+fn the_range_is_free() {
+    let mut game = game(42, 10, 0);
+    game.guess(50);
+
+    assert_eq!(game.hint(HintKind::Range), Ok(Hint::Range { low: 1, high: 49 }));
+    assert_eq!(game.attempts(), 1);
+    assert_eq!(game.hints_used(), 0);
+}
+
+#[test]
+fn refused_hints_cost_nothing() {
+    let mut off = game(42, 10, 0);
+    assert_eq!(off.hint(HintKind::Parity), Err(HintError::Disabled));
+
+    let mut spent = game(42, 10, 1);
+    spent.hint(HintKind::Parity).unwrap();
+    assert_eq!(spent.hint(HintKind::Parity), Err(HintError::NoHintsLeft));
+
+    // Spending the last attempt on a hint would lose the game.
+    let mut last = game(42, 2, 1);
+    last.guess(10);
+    assert_eq!(last.hint(HintKind::Parity), Err(HintError::NoAttemptToSpare));
+
+    let mut fresh = game(42, 10, 1);
+    assert_eq!(fresh.hint(HintKind::Near), Err(HintError::NoGuessYet));
+    assert_eq!(fresh.hint(HintKind::DivisibleBy(1)), Err(HintError::BadDivisor));
+
+    for (game, attempts) in [(&off, 0), (&spent, 1), (&last, 1), (&fresh, 0)] {
+        assert_eq!(game.attempts(), attempts);
+    }
+    assert_eq!(fresh.hints_left(), 1);
+}
+
+#[test]
+fn hints_tell_the_truth_about_the_secret() {
+    let mut odd = game(27, 10, 3);
+    assert_eq!(odd.hint(HintKind::Parity), Ok(Hint::Odd));
+    assert_eq!(
+        odd.hint(HintKind::DivisibleBy(9)),
+        Ok(Hint::DivisibleBy {
+            divisor: 9,
+            divisible: true
+        })
+    );
+    assert_eq!(
+        odd.hint(HintKind::DivisibleBy(2)),
+        Ok(Hint::DivisibleBy {
+            divisor: 2,
+            divisible: false
+        })
+    );
+}
+
+#[test]
+fn near_means_at_most_the_near_distance_away() {
+    let near = |secret, guess| {
+        let mut game = game(secret, 10, 1);
+        game.guess(guess);
+        game.hint(HintKind::Near)
+    };
+
+    assert_eq!(
+        near(50, 50 - NEAR_DISTANCE),
+        Ok(Hint::Near {
+            guess: 50 - NEAR_DISTANCE,
+            within: true
+        })
+    );
+    assert_eq!(
+        near(50, 50 + NEAR_DISTANCE),
+        Ok(Hint::Near {
+            guess: 50 + NEAR_DISTANCE,
+            within: true
+        })
+    );
+    assert_eq!(
+        near(50, 50 + NEAR_DISTANCE + 1),
+        Ok(Hint::Near {
+            guess: 50 + NEAR_DISTANCE + 1,
+            within: false
+        })
+    );
+}
+
+#[test]
+fn every_hint_used_adds_to_the_penalty() {
+    let mut game = game(42, 10, 3);
+    game.hint(HintKind::Parity).unwrap();
+    game.hint(HintKind::DivisibleBy(7)).unwrap();
+    game.hint(HintKind::Range).unwrap();
+    game.guess(42);
+
+    let score = Score::new("ferris", &game, Duration::from_secs(3), "normal", 5);
+
+    assert_eq!(game.hints_used(), 2);
+    assert_eq!(score.attempts, 3);
+    assert_eq!(score.penalty, 2 * 5);
+    assert_eq!(score.points(), 3 + 10);
+}
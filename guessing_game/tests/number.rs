@@ -0,0 +1,63 @@
+use guessing_game::Decimal;
+use guessing_game::number::DecimalError;
+
+#[test]
+fn negative_fractions_keep_their_sign() {
+    let half: Decimal<1> = "-0.5".parse().unwrap();
+
+    assert_eq!(half.units(), -5);
+    assert_eq!(half.to_string(), "-0.5");
+    assert!(half < Decimal::default());
+}
+
+#[test]
+fn the_whole_part_may_be_left_out() {
+    assert_eq!(".5".parse::<Decimal<2>>(), Ok(Decimal::from_units(50)));
+    assert_eq!("-.5".parse::<Decimal<2>>(), Ok(Decimal::from_units(-50)));
+    assert_eq!("5.".parse::<Decimal<2>>(), Ok(Decimal::from_units(500)));
+    assert_eq!(".".parse::<Decimal<2>>(), Err(DecimalError::Invalid));
+    assert_eq!("-".parse::<Decimal<2>>(), Err(DecimalError::Invalid));
+}
+
+#[test]
+fn trailing_zeros_are_not_extra_precision() {
+    assert_eq!("1.50".parse::<Decimal<1>>(), Ok(Decimal::from_units(15)));
+    assert_eq!("2.000".parse::<Decimal<0>>(), Ok(Decimal::from_units(2)));
+    assert_eq!(
+        "1.55".parse::<Decimal<1>>(),
+        Err(DecimalError::TooPrecise { digits: 1 })
+    );
+}
+
+#[test]
+fn numbers_past_i64_units_are_too_large() {
+    // i64::MAX is 9223372036854775807 units, or 922337203685477580.7.
+    assert_eq!(
+        "922337203685477580.7".parse::<Decimal<1>>(),
+        Ok(Decimal::from_units(i64::MAX))
+    );
+    assert_eq!(
+        "922337203685477580.8".parse::<Decimal<1>>(),
+        Err(DecimalError::TooLarge)
+    );
+    assert_eq!(
+        "99999999999999999999".parse::<Decimal<0>>(),
+        Err(DecimalError::TooLarge)
+    );
+}
+
+#[test]
+fn the_most_negative_units_round_trip() {
+    let min = Decimal::<1>::from_units(i64::MIN);
+
+    assert_eq!(min.to_string(), "-922337203685477580.8");
+    assert_eq!(min.to_string().parse::<Decimal<1>>(), Ok(min));
+    assert_eq!(
+        "-9223372036854775808".parse::<Decimal<0>>(),
+        Ok(Decimal::from_units(i64::MIN))
+    );
+    assert_eq!(
+        "-922337203685477580.9".parse::<Decimal<1>>(),
+        Err(DecimalError::TooLarge)
+    );
+}
@@ -0,0 +1,98 @@
+use std::cmp::Ordering;
+
+use guessing_game::reverse::{Answer, Contradiction, Reverse};
+use guessing_game::strategy::{BinarySearch, Random};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+fn binary(min: u32, max: u32) -> Reverse {
+    Reverse::new(min, max, Box::new(BinarySearch::new(min, max)))
+}
+
+/// What a player thinking of `secret` truthfully says about `guess`.
+fn honest(guess: u32, secret: u32) -> Answer {
+    match guess.cmp(&secret) {
+        Ordering::Less => Answer::Higher,
+        Ordering::Greater => Answer::Lower,
+        Ordering::Equal => Answer::Correct,
+    }
+}
+
+#[test]
+fn honest_answers_never_look_like_cheating() {
+    for secret in 1..=100 {
+        let strategies = [
+            binary(1, 100),
+            Reverse::new(1, 100, Box::new(Random::new(1, 100, StdRng::seed_from_u64(7)))),
+        ];
+        for mut reverse in strategies {
+            loop {
+                let guess = reverse.next_guess();
+                let found = reverse
+                    .answer(guess, honest(guess, secret))
+                    .unwrap_or_else(|err| panic!("{secret}: {err:?}"));
+                if found {
+                    assert_eq!(guess, secret);
+                    break;
+                }
+                assert!(reverse.guesses() <= 100, "{secret} is never found");
+            }
+        }
+    }
+}
+
+#[test]
+fn higher_then_lower_with_nothing_between_is_a_contradiction() {
+    let mut reverse = binary(1, 100);
+
+    assert_eq!(reverse.answer(50, Answer::Higher), Ok(false));
+    assert_eq!(
+        reverse.answer(51, Answer::Lower),
+        Err(Contradiction::Between {
+            higher_than: 50,
+            lower_than: 51
+        })
+    );
+    // The contradicting answer is not taken, so an honest one still works.
+    assert_eq!(reverse.answer(60, Answer::Lower), Ok(false));
+}
+
+#[test]
+fn lower_then_higher_is_caught_too() {
+    let mut reverse = binary(1, 100);
+
+    assert_eq!(reverse.answer(30, Answer::Lower), Ok(false));
+    assert_eq!(
+        reverse.answer(40, Answer::Higher),
+        Err(Contradiction::Between {
+            higher_than: 40,
+            lower_than: 30
+        })
+    );
+}
+
+#[test]
+fn nothing_is_higher_than_the_max() {
+    let mut reverse = binary(1, 100);
+
+    assert_eq!(
+        reverse.answer(100, Answer::Higher),
+        Err(Contradiction::AboveMax {
+            higher_than: 100,
+            max: 100
+        })
+    );
+}
+
+#[test]
+fn nothing_is_lower_than_the_min() {
+    let mut reverse = binary(1, 100);
+
+    assert_eq!(
+        reverse.answer(1, Answer::Lower),
+        Err(Contradiction::BelowMin {
+            lower_than: 1,
+            min: 1
+        })
+    );
+}
@@ -0,0 +1,106 @@
+//! Plays a round over loopback with two clients, the way `nc` would.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use guessing_game::{Config, Server};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+struct Client {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Client {
+    fn connect(addr: SocketAddr) -> Client {
+        let stream = TcpStream::connect(addr).unwrap();
+        // A missing line fails the test instead of hanging it.
+        stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        Client {
+            reader: BufReader::new(stream.try_clone().unwrap()),
+            writer: stream,
+        }
+    }
+
+    /// Connects and answers the name question.
+    fn join(addr: SocketAddr, name: &str) -> Client {
+        let mut client = Client::connect(addr);
+        assert_eq!(client.line(), "Welcome to the guessing game! What's your name?");
+        client.send(name);
+        client
+    }
+
+    fn send(&mut self, line: &str) {
+        writeln!(self.writer, "{line}").unwrap();
+    }
+
+    fn line(&mut self) -> String {
+        let mut line = String::new();
+        self.reader.read_line(&mut line).expect("the server answers");
+        line.trim_end().to_string()
+    }
+}
+
+fn start(args: &[&str]) -> SocketAddr {
+    let args = ["guessing_game"].iter().chain(args).map(|arg| arg.to_string());
+    let config = Config::build(args).unwrap();
+    // Seeded with 7, the first secret between 1 and 100 is 42.
+    let server = Server::bind("127.0.0.1:0", &config, StdRng::seed_from_u64(7)).unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+    addr
+}
+
+#[test]
+fn everyone_sees_every_guess_and_the_winner() {
+    let addr = start(&["--lang", "en"]);
+    let intro = "Round 1: I'm thinking of a number between 1 and 100. You have 10 attempts.";
+
+    let mut alice = Client::join(addr, "alice");
+    assert_eq!(alice.line(), intro);
+
+    let mut bob = Client::join(addr, "bob");
+    assert_eq!(alice.line(), "bob joined the game.");
+    assert_eq!(bob.line(), intro);
+
+    alice.send("50");
+    for client in [&mut alice, &mut bob] {
+        assert_eq!(client.line(), "alice guessed 50: too big!");
+    }
+
+    bob.send("42");
+    for client in [&mut alice, &mut bob] {
+        assert_eq!(client.line(), "bob guessed 42: correct! bob wins round 1!");
+        assert_eq!(
+            client.line(),
+            "Round 2: I'm thinking of a number between 1 and 100. You have 10 attempts."
+        );
+    }
+
+    bob.send("quit");
+    assert_eq!(alice.line(), "bob left the game.");
+}
+
+#[test]
+fn the_server_speaks_the_configured_language() {
+    let addr = start(&["--lang", "vi"]);
+
+    let mut lan = Client::connect(addr);
+    assert_eq!(lan.line(), "Chào mừng đến với trò chơi đoán số! Bạn tên là gì?");
+    lan.send("lan");
+    assert_eq!(
+        lan.line(),
+        "Vòng 1: Tôi đang nghĩ một số từ 1 đến 100. Bạn có 10 lượt đoán."
+    );
+
+    lan.send("hint parity");
+    assert_eq!(
+        lan.line(),
+        "Gợi ý đang tắt. Hãy bắt đầu trò chơi với --hints <N> để có gợi ý."
+    );
+    lan.send("50");
+    assert_eq!(lan.line(), "lan đoán 50: lớn quá!");
+}
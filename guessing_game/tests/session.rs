@@ -0,0 +1,67 @@
+use std::fs;
+use std::io::BufReader;
+use std::path::Path;
+
+use guessing_game::session::{self, Entry, Header, Mismatch, Recorder};
+use guessing_game::{Event, GuessOutcome, Session};
+use tempfile::TempDir;
+
+/// Records a game with `lines` as its input into `path`. With seed 7 the
+/// secret between 1 and 100 is 42.
+fn record(path: &Path, lines: &[&str]) -> Header {
+    let header = Header::new(7, 1, 100, 10, 0);
+    let recorder = Recorder::create(path, &header).unwrap();
+    let mut session = Session::start(&header, Some(recorder));
+
+    for line in lines {
+        session.handle_line(line);
+    }
+    session.end_of_input();
+    header
+}
+
+#[test]
+fn a_recorded_session_replays_the_same() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("sessions").join("game.jsonl");
+    let header = record(&path, &["abc", "50", "25", "help", "42"]);
+
+    let replay = session::replay(BufReader::new(fs::File::open(&path).unwrap())).unwrap();
+
+    assert_eq!(replay.header, header);
+    assert_eq!(replay.entries, 6);
+    assert_eq!(replay.mismatches, []);
+}
+
+#[test]
+fn a_tampered_entry_is_a_mismatch() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("game.jsonl");
+    record(&path, &["50", "25", "42"]);
+
+    // Claim the winning guess was 41 while keeping its recorded outcome.
+    let log = fs::read_to_string(&path).unwrap();
+    let mut lines: Vec<String> = log.lines().map(String::from).collect();
+    let mut entry: Entry = serde_json::from_str(&lines[3]).unwrap();
+    entry.input = Some("41".to_string());
+    lines[3] = serde_json::to_string(&entry).unwrap();
+
+    let replay = session::replay(lines.join("\n").as_bytes()).unwrap();
+
+    assert_eq!(replay.entries, 4);
+    assert_eq!(
+        replay.mismatches,
+        [Mismatch {
+            index: 3,
+            input: Some("41".to_string()),
+            recorded: Event::Guess {
+                guess: 42,
+                outcome: GuessOutcome::Correct
+            },
+            replayed: Event::Guess {
+                guess: 41,
+                outcome: GuessOutcome::TooSmall
+            },
+        }]
+    );
+}
@@ -0,0 +1,57 @@
+use std::cmp::Ordering;
+
+use guessing_game::strategy::{self, BinarySearch, HumanLike, Random, binary_search_bound};
+use guessing_game::{Game, Status, Strategy};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Plays `strategy` against `secret` in 1..=100 with attempts to spare,
+/// checking that every guess lies in what the answers so far leave open.
+/// Returns the attempts it took.
+fn play_within_range(secret: u32, strategy: &mut dyn Strategy) -> u32 {
+    let mut game = Game::with_secret(1, 100, secret, 100);
+    let (mut low, mut high) = (1, 100);
+
+    strategy::autoplay(&mut game, strategy, |guess, outcome| {
+        assert!(
+            (low..=high).contains(&guess),
+            "guessed {guess} for {secret}, but only {low}..={high} was left"
+        );
+        match outcome.ordering() {
+            Some(Ordering::Less) => low = guess + 1,
+            Some(Ordering::Greater) => high = guess - 1,
+            Some(Ordering::Equal) => {}
+            None => panic!("{guess} for {secret} was answered with {outcome:?}"),
+        }
+    });
+
+    assert_eq!(game.status(), Status::Won, "never found {secret}");
+    game.attempts()
+}
+
+#[test]
+fn binary_search_always_needs_at_most_seven_guesses() {
+    let bound = binary_search_bound(1, 100);
+    assert_eq!(bound, 7);
+
+    for secret in 1..=100 {
+        let attempts = play_within_range(secret, &mut BinarySearch::new(1, 100));
+        assert!(attempts <= bound, "{secret} took {attempts} attempts");
+    }
+}
+
+#[test]
+fn random_guesses_stay_in_the_narrowed_range() {
+    for secret in 1..=100 {
+        let rng = StdRng::seed_from_u64(u64::from(secret));
+        play_within_range(secret, &mut Random::new(1, 100, rng));
+    }
+}
+
+#[test]
+fn human_like_guesses_stay_in_the_narrowed_range() {
+    for secret in 1..=100 {
+        let rng = StdRng::seed_from_u64(u64::from(secret));
+        play_within_range(secret, &mut HumanLike::new(1, 100, rng));
+    }
+}
@@ -0,0 +1,86 @@
+use guessing_game::word::Collation;
+use guessing_game::{Config, Game, GuessOutcome, Mode};
+
+/// The collation `guessing_game words <flags>` plays with.
+fn collation(flags: &[&str]) -> Collation {
+    let args = ["guessing_game", "words"].iter().chain(flags).map(|arg| arg.to_string());
+    match Config::build(args).unwrap().mode {
+        Mode::Words { collation, .. } => collation,
+        mode => panic!("{flags:?} is not a words game: {mode:?}"),
+    }
+}
+
+/// What guessing `guess` answers when the secret is `secret`.
+fn answer(collation: Collation, secret: &str, guess: &str) -> GuessOutcome {
+    let mut game = Game::unbounded(collation.word(secret), 10);
+    game.guess(collation.word(guess))
+}
+
+// "é" typed as one character, and as "e" followed by a combining accent.
+const COMPOSED: &str = "\u{e9}clair";
+const DECOMPOSED: &str = "e\u{301}clair";
+
+#[test]
+fn accents_and_case_are_ignored_by_default() {
+    let collation = collation(&[]);
+
+    assert_eq!(collation.word("Éclair"), collation.word("eclair"));
+    assert_eq!(answer(collation, "eclair", "Éclair"), GuessOutcome::Correct);
+    assert_eq!(answer(collation, "eclair", DECOMPOSED), GuessOutcome::Correct);
+    // Without its accent, "éclair" sorts with the other e's, not after z.
+    assert_eq!(answer(collation, "zebra", "Éclair"), GuessOutcome::TooSmall);
+}
+
+#[test]
+fn case_sensitive_tells_upper_and_lower_case_apart() {
+    let collation = collation(&["--case-sensitive"]);
+
+    assert_ne!(collation.word("Eclair"), collation.word("eclair"));
+    // Upper case letters come before all the lower case ones.
+    assert_eq!(answer(collation, "apple", "Zebra"), GuessOutcome::TooSmall);
+    // The accents are still dropped.
+    assert_eq!(answer(collation, "Eclair", "Éclair"), GuessOutcome::Correct);
+}
+
+#[test]
+fn normalize_none_compares_code_points() {
+    let collation = collation(&["--normalize", "none"]);
+
+    assert_ne!(collation.word(COMPOSED), collation.word(DECOMPOSED));
+    assert_eq!(answer(collation, "zebra", COMPOSED), GuessOutcome::TooBig);
+    assert_eq!(answer(collation, "eclair", "ECLAIR"), GuessOutcome::Correct);
+}
+
+#[test]
+fn normalize_nfc_joins_accents_but_keeps_them() {
+    let collation = collation(&["--normalize", "nfc"]);
+
+    assert_eq!(collation.word(COMPOSED), collation.word(DECOMPOSED));
+    assert_ne!(collation.word(COMPOSED), collation.word("eclair"));
+    assert_ne!(collation.word("\u{fb01}g"), collation.word("fig"));
+}
+
+#[test]
+fn normalize_nfkc_also_unfolds_compatibility_characters() {
+    let collation = collation(&["--normalize", "nfkc"]);
+
+    assert_eq!(collation.word(COMPOSED), collation.word(DECOMPOSED));
+    // The "fi" ligature is one character.
+    assert_eq!(collation.word("\u{fb01}g"), collation.word("fig"));
+    assert_ne!(collation.word(COMPOSED), collation.word("eclair"));
+}
+
+#[test]
+fn normalize_strip_accents_is_the_default() {
+    let collation = collation(&["--normalize", "strip-accents"]);
+
+    assert_eq!(collation, Collation::default());
+    assert_eq!(collation.word(DECOMPOSED), collation.word("eclair"));
+    assert_eq!(collation.word("\u{fb01}g"), collation.word("fig"));
+}
+
+#[test]
+fn an_unknown_form_is_rejected() {
+    let args = ["guessing_game", "words", "--normalize", "nfd"].map(String::from);
+    assert!(Config::build(args.into_iter()).is_err());
+}
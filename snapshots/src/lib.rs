@@ -0,0 +1,106 @@
+//! Golden-output tests for the chapter binaries.
+//!
+//! Each chapter is run with `cargo run` and whatever it prints is compared
+//! with `golden/<chapter>.stdout`. The chapters have no tests of their own,
+//! so this is what notices when an edit changes what they print.
+//!
+//! When a change is on purpose, accept it by running the tests with
+//! `UPDATE_SNAPSHOTS=1` (or `make update-snapshots`), which rewrites the
+//! golden files, and commit them along with the change.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Set to anything to write the golden files instead of checking them.
+pub const UPDATE: &str = "UPDATE_SNAPSHOTS";
+
+/// The repository, one level up from this crate.
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+pub fn golden_path(chapter: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("golden")
+        .join(format!("{chapter}.stdout"))
+}
+
+/// Builds and runs a chapter without arguments and returns its output.
+///
+/// Panics if the chapter doesn't build or exits with an error, since there
+/// is nothing useful to compare then.
+pub fn run(chapter: &str) -> String {
+    // Cargo tells the tests it runs where it is.
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let output = Command::new(cargo)
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(root().join(chapter).join("Cargo.toml"))
+        .output()
+        .unwrap_or_else(|err| panic!("could not run cargo for {chapter}: {err}"));
+
+    assert!(
+        output.status.success(),
+        "{chapter} failed ({}):\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout)
+        .unwrap_or_else(|err| panic!("{chapter} printed something that isn't UTF-8: {err}"))
+}
+
+/// Runs a chapter and checks its output against the golden file, or
+/// rewrites the golden file in update mode.
+pub fn assert_golden(chapter: &str) {
+    let actual = run(chapter);
+    let path = golden_path(chapter);
+
+    if env::var_os(UPDATE).is_some() {
+        fs::write(&path, &actual)
+            .unwrap_or_else(|err| panic!("could not write {}: {err}", path.display()));
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "could not read {}: {err}\nRun with {UPDATE}=1 to create it.",
+            path.display()
+        )
+    });
+    if let Some(difference) = first_difference(&expected, &actual) {
+        panic!(
+            "{chapter} no longer prints what {} says.\n{difference}\n\
+             If the change is on purpose, run with {UPDATE}=1 to accept it.",
+            path.display()
+        );
+    }
+}
+
+/// Describes the first line where `expected` and `actual` part ways, or
+/// returns `None` if they are the same.
+fn first_difference(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut number = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(wanted), Some(got)) if wanted == got => number += 1,
+            (None, None) => return Some(String::from("Only the line endings differ.")),
+            (wanted, got) => {
+                let show = |line: Option<&str>| {
+                    line.map_or(String::from("(nothing)"), |line| format!("{line:?}"))
+                };
+                return Some(format!(
+                    "line {number}:\n  expected {}\n       got {}",
+                    show(wanted),
+                    show(got)
+                ));
+            }
+        }
+    }
+}
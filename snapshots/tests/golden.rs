@@ -0,0 +1,14 @@
+//! One test per chapter, so a failure names the chapter that changed.
+
+macro_rules! golden {
+    ($($chapter:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $chapter() {
+                snapshots::assert_golden(stringify!($chapter));
+            }
+        )*
+    };
+}
+
+golden!(branches, functions, variables, ownership, structs, hello_cargo);